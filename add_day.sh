@@ -3,19 +3,28 @@ if ! [[ $1 =~ ^[0-9]{1,2}$ ]] ; then
   echo "Not a valid arg" >&2; exit
 fi
 
+year=${2:-2022}
+if ! [[ $year =~ ^[0-9]{4}$ ]] ; then
+  echo "Not a valid year" >&2; exit
+fi
+
 day=day$(printf "%02d" "$1")
+module=y${year}
 
+# Later years are run with `aoc --year`, so only 2022 gets a binary per day
 binary=./src/bin/"${day}".rs
 
-if ! [[ -f "$binary" ]] ; then
+if [[ $year == 2022 ]] && ! [[ -f "$binary" ]] ; then
     cat <<< "use aoc2022::run;
 
 fn main() -> anyhow::Result<()> {
-    run!(${day})
+    run!(${module}::${day})
 }" >> "$binary"
 fi
 
-lib=./src/"${day}".rs
+mkdir -p ./src/"${module}" ./inputs/"${year}"
+
+lib=./src/"${module}"/"${day}".rs
 
 if ! [[ -f "$lib" ]] ; then
     cat <<< "pub fn run(input: &str) -> anyhow::Result<(u32, u32)> { 
@@ -23,8 +32,20 @@ if ! [[ -f "$lib" ]] ; then
 }" >> "$lib"
 fi
 
-if ! grep -q "${day}" ./src/lib.rs ; then
-  cat <<< "pub mod ${day};" >> ./src/lib.rs
+if ! [[ -f ./src/"${module}".rs ]] ; then
+  printf "//! Solutions to Advent of Code %s.\n\n" "${year}" >> ./src/"${module}".rs
+fi
+
+if ! grep -q "pub mod ${module};" ./src/lib.rs ; then
+  cat <<< "pub mod ${module};" >> ./src/lib.rs
+fi
+
+if ! grep -q "pub mod ${day};" ./src/"${module}".rs ; then
+  cat <<< "pub mod ${day};" >> ./src/"${module}".rs
+fi
+
+if ! grep -q "${module}::${day}," ./src/registry.rs ; then
+  sed -i "/^];$/i\\    solution!(${year}, ${module}::${day}, $((10#$1)))," ./src/registry.rs
 fi
 
 cargo fmt
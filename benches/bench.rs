@@ -3,9 +3,9 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 macro_rules! benches {
     ($($day:tt),*) => {
         $(pub fn $day(c: &mut Criterion) {
-            use aoc2022::$day::run;
+            use aoc2022::y2022::$day::run;
 
-            let input = include_str!(std::concat!("../inputs/", std::stringify!($day), ".txt"));
+            let input = include_str!(std::concat!("../inputs/2022/", std::stringify!($day), ".txt"));
 
             c.bench_function(std::stringify!($day), |b| b.iter(|| run(black_box(input))));
         })*
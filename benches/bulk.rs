@@ -0,0 +1,31 @@
+use aoc2022::y2022::{day03, generate};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Compares day03's bulk path with the rucksack by rucksack path on generated inputs of a few
+/// megabytes up to a few dozen.
+pub fn day03(c: &mut Criterion) {
+    let generator = generate::find("day03").unwrap();
+    let mut group = c.benchmark_group("day03_bulk");
+    group.sample_size(10);
+
+    for size in [100_000, 400_000, 1_600_000] {
+        let input = generator.seeded(0, size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("rucksacks", size), &input, |b, input| {
+            b.iter(|| {
+                day03::shared_items(black_box(input), 3)
+                    .unwrap()
+                    .priorities()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("bulk", size), &input, |b, input| {
+            b.iter(|| day03::bulk_priorities(black_box(input), 3).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day03);
+criterion_main!(benches);
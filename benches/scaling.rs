@@ -0,0 +1,36 @@
+use aoc2022::y2022::generate;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Benchmarks each day against generated inputs at half, one and two times the size of the real
+/// input.
+macro_rules! scaling {
+    ($($day:tt),*) => {
+        $(pub fn $day(c: &mut Criterion) {
+            use aoc2022::y2022::$day::run;
+
+            let generator = generate::find(std::stringify!($day)).unwrap();
+            let mut group = c.benchmark_group(std::concat!(std::stringify!($day), "_scaling"));
+            // The larger inputs can be slow, so keep the number of samples down
+            group.sample_size(10);
+
+            for size in [generator.default_size / 2, generator.default_size, generator.default_size * 2] {
+                let input = generator.seeded(0, size);
+
+                group.throughput(Throughput::Bytes(input.len() as u64));
+                group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+                    b.iter(|| run(black_box(input)))
+                });
+            }
+
+            group.finish();
+        })*
+
+        criterion_group!(benches, $($day),*);
+    };
+}
+
+scaling!(
+    day01, day02, day03, day04, day05, day06, day07, day09, day10, day13, day14, day18, day20,
+    day21, day23, day25
+);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+//! The known answers to the real puzzle inputs, so that runners can tell when a change breaks a
+//! solution.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use crate::registry::Answers;
+
+/// The answers in `answers.toml`, which has a table for each year with a table for each solution
+/// within it, e.g.
+///
+/// ```toml
+/// [2022.day01]
+/// part_1 = "66719"
+/// part_2 = "198551"
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    answers: HashMap<(u16, String), Answers>,
+}
+
+/// The path of the answers manifest within the inputs directory `dir`.
+pub fn path(dir: &Path) -> PathBuf {
+    dir.join("answers.toml")
+}
+
+impl Manifest {
+    /// Loads the manifest at `path`, which is empty if there is no such file.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let manifest = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        manifest
+            .parse()
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, name: &str) -> Option<&Answers> {
+        self.answers.get(&(year, name.to_string()))
+    }
+
+    /// Checks `answers` against the known answers of solution `name`, if there are any.
+    pub fn check(&self, year: u16, name: &str, answers: &Answers) -> anyhow::Result<()> {
+        let Some(expected) = self.get(year, name) else {
+            return Ok(());
+        };
+
+        for (part, answer, expected) in [
+            (1, &answers.part_1, &expected.part_1),
+            (2, &answers.part_2, &expected.part_2),
+        ] {
+            if answer != expected {
+                bail!("part {} is {:?}, expected {:?}", part, answer, expected);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Manifest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let years: HashMap<String, HashMap<String, Answers>> = toml::from_str(s)?;
+
+        let mut answers = HashMap::new();
+        for (year, solutions) in years {
+            let year: u16 = year
+                .parse()
+                .with_context(|| format!("invalid year {:?}", year))?;
+            for (name, solution_answers) in solutions {
+                answers.insert((year, name), solution_answers);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_answers_by_year_and_name() {
+        let manifest: Manifest = r#"
+[2022.day01]
+part_1 = "24000"
+part_2 = "45000"
+"#
+        .parse()
+        .unwrap();
+
+        assert!(manifest
+            .check(2022, "day01", &Answers::new(24000, 45000))
+            .is_ok());
+        assert!(manifest
+            .check(2022, "day01", &Answers::new(24000, 1))
+            .is_err());
+        // Solutions without known answers always pass
+        assert!(manifest.check(2021, "day01", &Answers::new(0, 0)).is_ok());
+    }
+
+    #[test]
+    fn only_has_answers_for_registered_solutions() {
+        let manifest = Manifest::load(&path(&crate::input::default_dir())).unwrap();
+
+        assert!(!manifest.answers.is_empty());
+        for (year, name) in manifest.answers.keys() {
+            assert!(crate::registry::find(*year, name).is_some());
+        }
+    }
+}
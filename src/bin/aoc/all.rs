@@ -0,0 +1,183 @@
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use aoc2022::registry::{Solution, SOLUTIONS};
+
+use crate::Cli;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+enum Status {
+    Ok,
+    Failed(String),
+    TimedOut,
+}
+
+struct Outcome {
+    status: Status,
+    elapsed: Duration,
+    stdout: String,
+    stderr: String,
+}
+
+/// Runs every registered solution of `--year`, or of every year if it isn't given, in a child
+/// process so that a panic, abort or runaway search in one day can't take the others down with it.
+/// Returns whether every day succeeded.
+pub fn run_all(cli: &Cli, timeout: Duration) -> anyhow::Result<bool> {
+    let exe = std::env::current_exe()?;
+
+    let mut ok = 0;
+    let mut failed = Vec::new();
+    let mut timed_out = Vec::new();
+
+    let solutions = SOLUTIONS
+        .iter()
+        .filter(|solution| cli.year.is_none_or(|year| solution.year == year));
+    for solution in solutions {
+        let outcome = run_isolated(&exe, solution, cli, timeout)?;
+        print_outcome(solution, &outcome);
+
+        let id = format!("{}/{}", solution.year, solution.name);
+        match outcome.status {
+            Status::Ok => ok += 1,
+            Status::Failed(_) => failed.push(id),
+            Status::TimedOut => timed_out.push(id),
+        }
+    }
+
+    println!();
+    println!(
+        "{} ok, {} failed, {} timed out",
+        ok,
+        failed.len(),
+        timed_out.len()
+    );
+    if !failed.is_empty() {
+        println!("failed: {}", failed.join(", "));
+    }
+    if !timed_out.is_empty() {
+        println!("timed out: {}", timed_out.join(", "));
+    }
+
+    Ok(failed.is_empty() && timed_out.is_empty())
+}
+
+fn run_isolated(
+    exe: &Path,
+    solution: &Solution,
+    cli: &Cli,
+    timeout: Duration,
+) -> anyhow::Result<Outcome> {
+    let start = Instant::now();
+    let mut command = Command::new(exe);
+    command
+        .arg("--inputs")
+        .arg(&cli.inputs)
+        .arg("--year")
+        .arg(solution.year.to_string())
+        .arg("run")
+        .arg(solution.name);
+    if cli.alloc {
+        command.arg("--alloc");
+    }
+    if cli.trace {
+        command.arg("--trace");
+    }
+    for _ in 0..cli.verbose {
+        command.arg("-v");
+    }
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // The pipes are drained on their own threads so that a chatty child can't block on a full pipe
+    // while we're waiting for it to exit.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let exit_status = loop {
+        if let Some(exit_status) = child.try_wait()? {
+            break Some(exit_status);
+        }
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    let elapsed = start.elapsed();
+
+    let stdout = stdout
+        .join()
+        .map_err(|_| anyhow!("failed to read stdout"))?;
+    let stderr = stderr
+        .join()
+        .map_err(|_| anyhow!("failed to read stderr"))?;
+
+    let status = match exit_status {
+        Some(exit_status) if exit_status.success() => Status::Ok,
+        Some(exit_status) => Status::Failed(failure_reason(&stderr, exit_status)),
+        None => Status::TimedOut,
+    };
+
+    Ok(Outcome {
+        status,
+        elapsed,
+        stdout,
+        stderr,
+    })
+}
+
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut buf);
+        }
+        buf
+    })
+}
+
+/// Picks the panic message or error out of the child's stderr, falling back to its exit status for
+/// children that died without saying why, e.g. on a stack overflow.
+fn failure_reason(stderr: &str, exit_status: ExitStatus) -> String {
+    let mut lines = stderr.lines();
+    while let Some(line) = lines.next() {
+        if line.contains("panicked at") {
+            return format!("panicked: {}", lines.next().unwrap_or_default().trim());
+        }
+        if let Some(error) = line.strip_prefix("Error: ") {
+            return error.to_string();
+        }
+    }
+
+    exit_status.to_string()
+}
+
+fn print_outcome(solution: &Solution, outcome: &Outcome) {
+    let (status, reason) = match &outcome.status {
+        Status::Ok => ("ok", ""),
+        Status::Failed(reason) => ("failed", reason.as_str()),
+        Status::TimedOut => ("timed out", ""),
+    };
+    let line = format!(
+        "{} {:<10} {:<10} {:>10.2?}  {}",
+        solution.year, solution.name, status, outcome.elapsed, reason
+    );
+    println!("{}", line.trim_end());
+
+    if let Status::Ok = outcome.status {
+        // Successful days only write to stderr when logging is enabled
+        for line in outcome.stdout.lines().chain(outcome.stderr.lines()) {
+            println!("    {}", line);
+        }
+    }
+}
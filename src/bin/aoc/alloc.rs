@@ -0,0 +1,149 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering::Relaxed};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+// Signed, as memory allocated before counting was enabled may be freed while counting
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// Wraps the system allocator, counting allocations once `enable` has been called. Until then the
+/// only overhead is checking whether it has been.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Relaxed) {
+            CURRENT.fetch_sub(layout.size() as isize, Relaxed);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Relaxed) {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Records an allocation of `size` bytes that replaced `freed` bytes
+#[inline]
+fn record(size: usize, freed: usize) {
+    COUNT.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let current = CURRENT.fetch_add(size as isize - freed as isize, Relaxed);
+    PEAK.fetch_max(current + size as isize - freed as isize, Relaxed);
+}
+
+pub fn enable() {
+    ENABLED.store(true, Relaxed);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    /// The number of allocations, including reallocations
+    pub count: usize,
+    /// The total number of bytes allocated
+    pub bytes: usize,
+    /// The most heap in use at once, relative to the start of the measurement
+    pub peak: usize,
+}
+
+/// Measures the allocations made while running `f`. Measurements don't nest, and allocations made
+/// on other threads at the same time are counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    COUNT.store(0, Relaxed);
+    BYTES.store(0, Relaxed);
+    let base = CURRENT.load(Relaxed);
+    PEAK.store(base, Relaxed);
+
+    let out = f();
+
+    let stats = Stats {
+        count: COUNT.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        peak: (PEAK.load(Relaxed) - base).max(0) as usize,
+    };
+
+    (out, stats)
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_allocations() {
+        enable();
+
+        let (v, stats) = measure(|| {
+            let mut v = Vec::with_capacity(1024);
+            v.extend(std::iter::repeat_n(1u8, 4096));
+            v
+        });
+
+        assert_eq!(4096, v.len());
+        assert!(stats.count >= 2);
+        assert!(stats.bytes >= 1024 + 4096);
+    }
+
+    #[test]
+    fn formats_sizes() {
+        assert_eq!("512 B", Bytes(512).to_string());
+        assert_eq!("1.5 KiB", Bytes(1536).to_string());
+        assert_eq!("3.0 MiB", Bytes(3 * 1024 * 1024).to_string());
+    }
+}
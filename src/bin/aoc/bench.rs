@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context};
+use serde_json::Value;
+
+/// The change in a benchmark's mean time between a saved baseline and the latest run.
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    /// The benchmark's path within the criterion directory, e.g. `day01` or `day01_scaling/1000`
+    pub id: String,
+    pub baseline_ns: f64,
+    pub current_ns: f64,
+}
+
+impl Change {
+    pub fn percent(&self) -> f64 {
+        (self.current_ns - self.baseline_ns) / self.baseline_ns * 100.0
+    }
+}
+
+/// Runs the benchmarks with `cargo bench`, passing `filter` and `criterion_args` through to
+/// criterion.
+pub fn cargo_bench(
+    bench: &str,
+    filter: Option<&str>,
+    criterion_args: &[&str],
+) -> anyhow::Result<()> {
+    let status = Command::new(env!("CARGO"))
+        .args(["bench", "--bench", bench, "--"])
+        .args(filter)
+        .args(criterion_args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .context("failed to run cargo bench")?;
+
+    if !status.success() {
+        bail!("cargo bench failed with {}", status);
+    }
+
+    Ok(())
+}
+
+/// Compares the latest run of every benchmark under `criterion_dir` against `baseline`, skipping
+/// benchmarks that weren't part of both. Only benchmarks whose ids contain `filter` are compared,
+/// as the others' latest results are from earlier runs.
+pub fn compare(
+    criterion_dir: &Path,
+    baseline: &str,
+    filter: Option<&str>,
+) -> anyhow::Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    collect_changes(criterion_dir, criterion_dir, baseline, &mut changes)?;
+    if let Some(filter) = filter {
+        changes.retain(|change| change.id.contains(filter));
+    }
+    changes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Ok(changes)
+}
+
+fn collect_changes(
+    root: &Path,
+    dir: &Path,
+    baseline: &str,
+    changes: &mut Vec<Change>,
+) -> anyhow::Result<()> {
+    let current = dir.join("new").join("estimates.json");
+    let saved = dir.join(baseline).join("estimates.json");
+
+    if current.is_file() {
+        if saved.is_file() {
+            changes.push(Change {
+                id: dir.strip_prefix(root)?.to_string_lossy().into_owned(),
+                baseline_ns: mean_estimate(&saved)?,
+                current_ns: mean_estimate(&current)?,
+            });
+        }
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        // Criterion keeps its HTML report alongside the benchmarks
+        if path.is_dir() && !path.ends_with("report") {
+            collect_changes(root, &path, baseline, changes)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn mean_estimate(path: &Path) -> anyhow::Result<f64> {
+    let estimates: Value = serde_json::from_str(&fs::read_to_string(path)?)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+
+    estimates["mean"]["point_estimate"]
+        .as_f64()
+        .with_context(|| format!("no mean estimate in {}", path.display()))
+}
+
+/// Prints each change, returning whether none of them regressed by more than `threshold` percent.
+pub fn report(changes: &[Change], threshold: f64) -> bool {
+    let mut regressions = 0;
+
+    for change in changes {
+        let percent = change.percent();
+        let verdict = if percent > threshold {
+            regressions += 1;
+            "regressed"
+        } else if percent < -threshold {
+            "improved"
+        } else {
+            ""
+        };
+
+        let line = format!(
+            "{:<24} {:>12} {:>12} {:>+8.2}%  {}",
+            change.id,
+            format_ns(change.baseline_ns),
+            format_ns(change.current_ns),
+            percent,
+            verdict
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!();
+    println!(
+        "{} benchmarks compared, {} regressed by more than {}%",
+        changes.len(),
+        regressions,
+        threshold
+    );
+
+    regressions == 0
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2} s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2} ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{:.2} ns", ns)
+    }
+}
+
+pub fn default_criterion_dir() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/target/criterion"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_estimate(dir: &Path, mean: f64) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            format!(
+                r#"{{"mean":{{"point_estimate":{}}},"median":{{"point_estimate":0.0}}}}"#,
+                mean
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compares_against_baseline() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        write_estimate(&dir.join("day01/main"), 100.0);
+        write_estimate(&dir.join("day01/new"), 150.0);
+        write_estimate(&dir.join("day01_scaling/1000/main"), 200.0);
+        write_estimate(&dir.join("day01_scaling/1000/new"), 100.0);
+        // Only in the latest run, so there's nothing to compare against
+        write_estimate(&dir.join("day02/new"), 100.0);
+        fs::create_dir_all(dir.join("report")).unwrap();
+
+        let changes = compare(&dir, "main", None).unwrap();
+        let filtered = compare(&dir, "main", Some("scaling")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(2, changes.len());
+        assert_eq!("day01", changes[0].id);
+        assert_eq!(50.0, changes[0].percent());
+        assert_eq!(Path::new("day01_scaling/1000"), Path::new(&changes[1].id));
+        assert_eq!(-50.0, changes[1].percent());
+        // Benchmarks outside the filter weren't part of the latest run
+        assert_eq!(1, filtered.len());
+        assert_eq!(changes[1], filtered[0]);
+
+        assert!(!report(&changes, 10.0));
+        assert!(report(&changes, 60.0));
+    }
+}
@@ -0,0 +1,396 @@
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use aoc2022::answers::{self, Manifest};
+use aoc2022::image::Gradient;
+use aoc2022::input;
+use aoc2022::registry::{self, Answers, Params, Solution};
+use aoc2022::viz::{Animation, Speed};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+mod all;
+mod alloc;
+mod bench;
+mod serve;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    /// The directory containing the puzzle inputs
+    #[arg(long, global = true, default_value_os_t = input::default_dir())]
+    inputs: PathBuf,
+
+    /// The year of the puzzles, which defaults to the latest year with any solutions
+    #[arg(long, global = true)]
+    year: Option<u16>,
+
+    /// Reports the number of allocations, bytes allocated and peak heap use of loading and solving
+    #[arg(long, global = true)]
+    alloc: bool,
+
+    /// Logs solver diagnostics to stderr, `-v` for info and `-vv` for debug
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Logs everything, including how long each span took
+    #[arg(long, global = true)]
+    trace: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a single day, given by number (`8`) or by name (`day08_par`)
+    Run {
+        day: String,
+
+        /// Reads the input from this file instead of the inputs directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Streams the input rather than reading it into memory, for days that support it. An
+        /// `--input` of `-` streams from stdin.
+        #[arg(long)]
+        stream: bool,
+
+        /// Sets a parameter that the day takes, e.g. `--param capacity=2` for day05. The answers are
+        /// only checked when no parameters are set.
+        #[arg(
+            long = "param",
+            value_name = "NAME=VALUE",
+            value_parser = parse_param,
+            conflicts_with = "stream"
+        )]
+        params: Vec<(String, i64)>,
+
+        #[command(flatten)]
+        viz: VizArgs,
+
+        #[command(flatten)]
+        image: ImageArgs,
+    },
+    /// Runs every day of every year, or only of `--year`, in its own process, reporting panics,
+    /// errors, wrong answers and timeouts
+    All {
+        /// The number of seconds each day may run for before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Serves the solutions over HTTP on localhost, answering `POST /{year}/day/{n}`, or
+    /// `POST /day/{n}` for the latest year, with the input as the body
+    Serve {
+        #[arg(long, default_value_t = 8022)]
+        port: u16,
+    },
+    /// Saves benchmark baselines and compares later runs against them
+    #[command(subcommand)]
+    Bench(BenchCommand),
+    /// Re-runs a day whenever its input or example changes, printing how the answers changed
+    Watch {
+        day: String,
+
+        /// Watches these files instead of the day's input and example
+        #[arg(long = "file")]
+        files: Vec<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct VizArgs {
+    /// Plays the day's simulation in the terminal before solving, for days that have one
+    #[arg(long, conflicts_with = "stream")]
+    viz: bool,
+
+    /// The frames per second to play the simulation at, where 0 is as fast as possible
+    #[arg(long, default_value_t = 30.0, requires = "viz")]
+    fps: f64,
+
+    /// The number of steps of the simulation between frames
+    #[arg(long, default_value_t = 1, requires = "viz")]
+    steps_per_frame: usize,
+
+    /// The most columns and rows to draw, beyond which the simulation is cropped
+    #[arg(long, default_value = "120x40", value_parser = parse_viewport, requires = "viz")]
+    viewport: (usize, usize),
+}
+
+#[derive(Args)]
+struct ImageArgs {
+    /// Saves a picture of the day's grid to this `.png` or `.ppm` file before solving, for days
+    /// that have one
+    #[arg(long, conflicts_with = "stream")]
+    image: Option<PathBuf>,
+
+    /// The palette to colour the picture's maps with: greyscale, heat or terrain
+    #[arg(long, default_value = "heat", value_parser = parse_palette, requires = "image")]
+    palette: Gradient,
+
+    /// The width and height in pixels to draw each cell of the grid with
+    #[arg(long, default_value_t = 4, requires = "image")]
+    scale: usize,
+}
+
+fn parse_palette(name: &str) -> anyhow::Result<Gradient> {
+    Gradient::named(name).ok_or_else(|| anyhow!("expected one of {}", Gradient::NAMES.join(", ")))
+}
+
+fn parse_param(param: &str) -> anyhow::Result<(String, i64)> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected NAME=VALUE, e.g. capacity=2"))?;
+
+    Ok((name.to_string(), value.parse()?))
+}
+
+fn parse_viewport(viewport: &str) -> anyhow::Result<(usize, usize)> {
+    let (columns, rows) = viewport
+        .split_once('x')
+        .ok_or_else(|| anyhow!("expected COLUMNSxROWS, e.g. 120x40"))?;
+
+    Ok((columns.parse()?, rows.parse()?))
+}
+
+#[derive(Subcommand)]
+enum BenchCommand {
+    /// Runs the benchmarks and saves the results as a named baseline
+    Save {
+        name: String,
+
+        /// Only runs benchmarks matching this criterion filter, e.g. `day0`
+        filter: Option<String>,
+
+        /// The criterion bench target to run
+        #[arg(long, default_value = "bench")]
+        bench: String,
+    },
+    /// Compares the benchmarks against a saved baseline, failing if any regressed
+    Compare {
+        name: String,
+
+        /// Only runs benchmarks matching this criterion filter, e.g. `day0`
+        filter: Option<String>,
+
+        /// The criterion bench target to run
+        #[arg(long, default_value = "bench")]
+        bench: String,
+
+        /// The percentage slowdown that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Compares the results of the last run instead of running the benchmarks again
+        #[arg(long)]
+        no_run: bool,
+
+        /// Where criterion keeps its results
+        #[arg(long, default_value_os_t = bench::default_criterion_dir())]
+        criterion_dir: PathBuf,
+    },
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let cli = Cli::parse();
+    init_logging(&cli);
+
+    match cli.command {
+        Command::Run {
+            day,
+            input,
+            stream,
+            params,
+            viz,
+            image,
+        } => {
+            let solution = find(cli.year, &day)?;
+            let params: Params = params.into_iter().collect();
+            // Answers are only known for the real inputs and parameters
+            let check = input.is_none() && params.is_empty();
+            let path =
+                input.unwrap_or_else(|| input::path(&cli.inputs, solution.year, solution.name));
+            if cli.alloc {
+                alloc::enable();
+            }
+
+            if viz.viz {
+                play(solution, &path, &params, &viz)?;
+            }
+            if let Some(out) = &image.image {
+                draw(solution, &path, &params, out, &image)?;
+            }
+
+            let (answers, load, solve) = if stream {
+                let stream = solution
+                    .stream
+                    .ok_or_else(|| anyhow!("{} can't be streamed", solution.name))?;
+                let (reader, load) = alloc::measure(|| input::open(&path));
+                let mut reader = reader?;
+                let (answers, solve) = alloc::measure(|| stream(&mut reader));
+                (answers?, load, solve)
+            } else {
+                let (input, load) = alloc::measure(|| input::load(&path));
+                let input = input?;
+                let (answers, solve) = alloc::measure(|| solution.solve_with(&input, &params));
+                (answers?, load, solve)
+            };
+
+            print_answers(&answers);
+            if cli.alloc {
+                println!("Load:  {}", load);
+                println!("Solve: {}", solve);
+            }
+
+            if check {
+                Manifest::load(&answers::path(&cli.inputs))?
+                    .check(solution.year, solution.name, &answers)
+                    .context("wrong answer")?;
+            }
+
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::All { timeout } => {
+            let all_ok = all::run_all(&cli, Duration::from_secs(timeout))?;
+
+            Ok(if all_ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::Serve { port } => {
+            serve::serve(port)?;
+
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Bench(BenchCommand::Save {
+            name,
+            filter,
+            bench,
+        }) => {
+            bench::cargo_bench(&bench, filter.as_deref(), &["--save-baseline", &name])?;
+
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Bench(BenchCommand::Compare {
+            name,
+            filter,
+            bench,
+            threshold,
+            no_run,
+            criterion_dir,
+        }) => {
+            if !no_run {
+                // Unlike `--save-baseline`, `--baseline` leaves the saved baseline untouched
+                bench::cargo_bench(&bench, filter.as_deref(), &["--baseline", &name])?;
+            }
+
+            let changes = bench::compare(&criterion_dir, &name, filter.as_deref())?;
+            if changes.is_empty() {
+                return Err(anyhow!("no benchmarks to compare against {}", name));
+            }
+
+            Ok(if bench::report(&changes, threshold) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::Watch { day, mut files } => {
+            let solution = find(cli.year, &day)?;
+            if files.is_empty() {
+                files.push(input::path(&cli.inputs, solution.year, solution.name));
+                files.push(input::example_path(
+                    &cli.inputs,
+                    solution.year,
+                    solution.name,
+                ));
+            }
+            watch::watch(solution, files)?;
+
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn init_logging(cli: &Cli) {
+    let level = match (cli.trace, cli.verbose) {
+        (true, _) => Level::TRACE,
+        (false, 0) => Level::WARN,
+        (false, 1) => Level::INFO,
+        (false, 2) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+    let span_events = if cli.trace {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+
+    // Logs go to stderr so that they never get mixed up with the answers
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(span_events)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr)
+        .init();
+}
+
+fn find(year: Option<u16>, day: &str) -> anyhow::Result<&'static Solution> {
+    let year = year.unwrap_or_else(registry::latest_year);
+    registry::find(year, day).ok_or_else(|| anyhow!("no solution for {} of {}", day, year))
+}
+
+fn play(solution: &Solution, path: &Path, params: &Params, args: &VizArgs) -> anyhow::Result<()> {
+    let viz = solution
+        .viz
+        .ok_or_else(|| anyhow!("{} has no visualisation", solution.name))?;
+    let input = input::load(path)?;
+
+    let speed = Speed {
+        fps: args.fps,
+        steps_per_frame: args.steps_per_frame,
+    };
+    let mut stdout = io::stdout().lock();
+    let ansi = stdout.is_terminal();
+    let mut animation = Animation::new(&mut stdout, speed, args.viewport, ansi);
+
+    viz(&input, params, &mut animation)
+}
+
+fn draw(
+    solution: &Solution,
+    path: &Path,
+    params: &Params,
+    out: &Path,
+    args: &ImageArgs,
+) -> anyhow::Result<()> {
+    let draw = solution
+        .image
+        .ok_or_else(|| anyhow!("{} has no image", solution.name))?;
+    let input = input::load(path)?;
+
+    draw(&input, params, &args.palette)?
+        .scale(args.scale)
+        .save(out)
+}
+
+fn print_answers(answers: &Answers) {
+    for (part, answer) in [(1, &answers.part_1), (2, &answers.part_2)] {
+        // Multi-line answers such as day10's CRT image start on their own line.
+        if answer.contains('\n') {
+            print!("Part {}:\n{}", part, answer);
+        } else {
+            println!("Part {}: {}", part, answer);
+        }
+    }
+}
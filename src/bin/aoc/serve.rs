@@ -0,0 +1,238 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::Instant;
+
+use aoc2022::{input, registry};
+use serde_json::json;
+
+/// Puzzle inputs are at most a few tens of kilobytes, so anything this large is a mistake.
+const MAX_BODY_LEN: usize = 64 * 1024 * 1024;
+
+pub fn serve(port: u16) -> anyhow::Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+    println!("listening on http://{}", listener.local_addr()?);
+
+    serve_on(listener)
+}
+
+/// Handles each connection on its own thread, answering `POST /{year}/day/{n}` with the answers to
+/// the posted input, where `n` is anything that `registry::find` accepts. `POST /day/{n}` is for the
+/// latest year.
+pub fn serve_on(listener: TcpListener) -> anyhow::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            let _ = handle(stream);
+        });
+    }
+
+    Ok(())
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }).to_string(),
+        }
+    }
+}
+
+fn handle(stream: TcpStream) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let response = respond(&mut reader)?;
+
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    };
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+fn respond<R: BufRead>(reader: &mut R) -> anyhow::Result<Response> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Ok(Response::error(400, "malformed request line"));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                match value.trim().parse() {
+                    Ok(len) => content_length = len,
+                    Err(_) => return Ok(Response::error(400, "invalid Content-Length")),
+                }
+            }
+        }
+    }
+
+    let Some((year, day)) = route(target) else {
+        return Ok(Response::error(404, "expected /{year}/day/{n} or /day/{n}"));
+    };
+    let Some(solution) = registry::find(year, day) else {
+        return Ok(Response::error(
+            404,
+            &format!("no solution for {} of {}", day, year),
+        ));
+    };
+    if method != "POST" {
+        return Ok(Response::error(405, "expected POST"));
+    }
+    if content_length > MAX_BODY_LEN {
+        return Ok(Response::error(413, "input too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let Ok(input) = String::from_utf8(body) else {
+        return Ok(Response::error(400, "input is not valid UTF-8"));
+    };
+    let input = input::normalise(&input);
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(&input)));
+    let elapsed = start.elapsed();
+
+    Ok(match result {
+        Ok(Ok(answers)) => Response {
+            status: 200,
+            body: json!({
+                "year": solution.year,
+                "name": solution.name,
+                "day": solution.day,
+                "part_1": answers.part_1,
+                "part_2": answers.part_2,
+                "elapsed_us": elapsed.as_micros() as u64,
+            })
+            .to_string(),
+        },
+        Ok(Err(err)) => Response::error(422, &format!("{:#}", err)),
+        Err(_) => Response::error(500, "solution panicked"),
+    })
+}
+
+/// Splits a target of `/{year}/day/{n}` or `/day/{n}` into its year and day.
+fn route(target: &str) -> Option<(u16, &str)> {
+    if let Some(day) = target.strip_prefix("/day/") {
+        return Some((registry::latest_year(), day));
+    }
+
+    let (year, day) = target.strip_prefix('/')?.split_once("/day/")?;
+    Some((year.parse().ok()?, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Read;
+    use std::net::SocketAddr;
+
+    fn spawn_server() -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve_on(listener));
+        addr
+    }
+
+    fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn solves_posted_input() {
+        let addr = spawn_server();
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        let (status, body) = request(addr, "POST", "/day/1", input);
+
+        assert_eq!(status, 200);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["year"], 2022);
+        assert_eq!(body["name"], "day01");
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part_1"], "24000");
+        assert_eq!(body["part_2"], "45000");
+        assert!(body["elapsed_us"].is_u64());
+        assert_eq!(request(addr, "POST", "/2022/day/1", input).0, 200);
+    }
+
+    #[test]
+    fn reports_unknown_days_and_methods() {
+        let addr = spawn_server();
+
+        assert_eq!(request(addr, "POST", "/day/26", "").0, 404);
+        assert_eq!(request(addr, "POST", "/", "").0, 404);
+        assert_eq!(request(addr, "POST", "/2015/day/1", "").0, 404);
+        assert_eq!(request(addr, "POST", "/year/day/1", "").0, 404);
+        assert_eq!(request(addr, "GET", "/day/1", "").0, 405);
+    }
+
+    #[test]
+    fn survives_panicking_solutions() {
+        let addr = spawn_server();
+
+        let (status, body) = request(addr, "POST", "/day/13", "[1,[2\n");
+        assert_eq!(status, 500);
+        assert_eq!(body, r#"{"error":"solution panicked"}"#);
+
+        // Messages are escaped, whatever they contain
+        let (status, body) = request(addr, "POST", "/day/5", "\"\n\\");
+        assert_eq!(status, 422);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(body["error"].is_string());
+
+        assert_eq!(
+            request(addr, "POST", "/day/6", "mjqjpqmgbljsphdztnvjfqwrcgsmlb").0,
+            200
+        );
+    }
+}
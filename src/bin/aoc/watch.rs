@@ -0,0 +1,111 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc2022::input;
+use aoc2022::registry::{Answers, Solution};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+struct Watched {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    previous: Option<Answers>,
+}
+
+/// Re-runs `solution` on each of `paths` whenever it is modified, printing how the answers differ
+/// from the previous run on the same file. Paths that don't exist yet are picked up once created.
+pub fn watch(solution: &Solution, paths: Vec<PathBuf>) -> anyhow::Result<()> {
+    for path in &paths {
+        println!("watching {}", path.display());
+    }
+
+    let mut watched: Vec<_> = paths
+        .into_iter()
+        .map(|path| Watched {
+            path,
+            modified: None,
+            previous: None,
+        })
+        .collect();
+
+    loop {
+        for file in &mut watched {
+            let modified = fs::metadata(&file.path).and_then(|m| m.modified()).ok();
+            if modified.is_some() && modified != file.modified {
+                file.modified = modified;
+                file.run(solution);
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+impl Watched {
+    fn run(&mut self, solution: &Solution) {
+        println!();
+        println!("--- {}", self.path.display());
+
+        let input = match input::load(&self.path) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("error: {:#}", err);
+                return;
+            }
+        };
+
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(&input)));
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(Ok(answers)) => {
+                let previous = self.previous.as_ref();
+                println!(
+                    "{}",
+                    describe(1, previous.map(|p| p.part_1.as_str()), &answers.part_1)
+                );
+                println!(
+                    "{}",
+                    describe(2, previous.map(|p| p.part_2.as_str()), &answers.part_2)
+                );
+                println!("({:.2?})", elapsed);
+                self.previous = Some(answers);
+            }
+            Ok(Err(err)) => println!("error: {:#}", err),
+            Err(_) => println!("panicked"),
+        }
+    }
+}
+
+/// Describes an answer along with how it changed since the previous run, if there was one.
+fn describe(part: u8, previous: Option<&str>, current: &str) -> String {
+    let multi_line = current.contains('\n');
+    let change = match previous {
+        None => String::new(),
+        Some(previous) if previous == current => " (unchanged)".to_string(),
+        Some(previous) if multi_line || previous.contains('\n') => " (changed)".to_string(),
+        Some(previous) => format!(" (was {})", previous),
+    };
+
+    if multi_line {
+        format!("Part {}:{}\n{}", part, change, current.trim_end())
+    } else {
+        format!("Part {}: {}{}", part, current, change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_changes_between_runs() {
+        assert_eq!(describe(1, None, "31"), "Part 1: 31");
+        assert_eq!(describe(1, Some("31"), "31"), "Part 1: 31 (unchanged)");
+        assert_eq!(describe(2, Some("30"), "29"), "Part 2: 29 (was 30)");
+        assert_eq!(describe(2, Some("#.\n"), "##\n"), "Part 2: (changed)\n##");
+    }
+}
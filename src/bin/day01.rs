@@ -1,5 +1,5 @@
 use aoc2022::run;
 
 fn main() -> anyhow::Result<()> {
-    run!(day01)
+    run!(y2022::day01)
 }
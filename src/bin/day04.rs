@@ -1,5 +1,5 @@
 use aoc2022::run;
 
 fn main() -> anyhow::Result<()> {
-    run!(day04)
+    run!(y2022::day04)
 }
@@ -1,8 +1,9 @@
-use aoc2022::day10::run_with;
+use aoc2022::input;
+use aoc2022::y2022::day10::run_with;
 
 fn main() -> anyhow::Result<()> {
-    let input = include_str!("../../inputs/day10.txt");
+    let input = input::load(&input::path(&input::default_dir(), 2022, "day10"))?;
 
     let mut out = std::io::stdout().lock();
-    run_with(input, &mut out)
+    run_with(&input, &mut out)
 }
@@ -1,10 +1,9 @@
-use aoc2022::run;
-
 fn main() -> anyhow::Result<()> {
-    let alt_input = include_str!("../../inputs/day21_alt.txt");
-    use aoc2022::day21;
+    use aoc2022::input;
+    use aoc2022::y2022::day21;
+    let alt_input = input::load(&input::path(&input::default_dir(), 2022, "day21_alt"))?;
 
-    let (p1, p2) = day21::run(alt_input)?;
+    let (p1, p2) = day21::run(&alt_input)?;
 
     println!("p1 {}", p1);
     println!("p2 {}", p2);
@@ -0,0 +1,26 @@
+use aoc2022::y2022::generate;
+
+/// Prints a generated input for a day, e.g. `generate day05 42 1000`. The seed defaults to 0 and
+/// the size to that of the real input.
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+
+    let name = args.next().ok_or_else(|| anyhow::anyhow!("missing day"))?;
+    let generator =
+        generate::find(&name).ok_or_else(|| anyhow::anyhow!("no generator for {}", name))?;
+
+    let seed = args
+        .next()
+        .map(|seed| seed.parse())
+        .transpose()?
+        .unwrap_or(0);
+    let size = args
+        .next()
+        .map(|size| size.parse())
+        .transpose()?
+        .unwrap_or(generator.default_size);
+
+    print!("{}", generator.seeded(seed, size));
+
+    Ok(())
+}
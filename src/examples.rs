@@ -0,0 +1,155 @@
+//! The examples from the puzzle descriptions and their answers, listed in `examples.toml` so that
+//! new examples can be checked without writing any Rust.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use crate::input;
+use crate::registry::{Answers, Params};
+
+/// An example, with its input given either as a `file` or inline, any `params` it needs and the
+/// answers to whichever parts it's an example of.
+///
+/// ```toml
+/// [[2022.day15]]
+/// file = "day15_test.txt"
+/// params = { row = 10, bound = 20 }
+/// part_1 = "26"
+/// part_2 = "56000011"
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// The example's input file, relative to the inputs directory of its year
+    pub file: Option<String>,
+    /// The example's input, for examples short enough to give inline
+    pub input: Option<String>,
+    #[serde(default)]
+    pub params: Params,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// The path of the examples manifest within the inputs directory `dir`.
+pub fn path(dir: &Path) -> PathBuf {
+    dir.join("examples.toml")
+}
+
+impl Example {
+    /// Loads and normalises the example's input, looking for its file in the inputs directory
+    /// `dir`.
+    pub fn load(&self, dir: &Path, year: u16) -> anyhow::Result<String> {
+        match (&self.file, &self.input) {
+            (Some(file), None) => input::load(&dir.join(year.to_string()).join(file)),
+            (None, Some(example)) => Ok(input::normalise(example)),
+            _ => bail!("an example needs either a file or an input"),
+        }
+    }
+
+    /// Checks `answers` against the answers to the parts that this is an example of.
+    pub fn check(&self, answers: &Answers) -> anyhow::Result<()> {
+        for (part, answer, expected) in [
+            (1, &answers.part_1, &self.part_1),
+            (2, &answers.part_2, &self.part_2),
+        ] {
+            if let Some(expected) = expected {
+                if answer != expected {
+                    bail!("part {} is {:?}, expected {:?}", part, answer, expected);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Every example, keyed by year and solution name.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    examples: BTreeMap<(u16, String), Vec<Example>>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let manifest = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        manifest
+            .parse()
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, name: &str) -> &[Example] {
+        self.examples
+            .get(&(year, name.to_string()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Every example in order of year and solution name, along with its year and solution name.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &str, &Example)> {
+        self.examples.iter().flat_map(|((year, name), examples)| {
+            examples
+                .iter()
+                .map(move |example| (*year, name.as_str(), example))
+        })
+    }
+}
+
+impl std::str::FromStr for Manifest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let years: HashMap<String, HashMap<String, Vec<Example>>> = toml::from_str(s)?;
+
+        let mut examples = BTreeMap::new();
+        for (year, solutions) in years {
+            let year: u16 = year
+                .parse()
+                .with_context(|| format!("invalid year {:?}", year))?;
+            for (name, solution_examples) in solutions {
+                examples.insert((year, name), solution_examples);
+            }
+        }
+
+        Ok(Self { examples })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_examples() {
+        let manifest: Manifest = r#"
+[[2022.day06]]
+input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb"
+part_1 = "7"
+
+[[2022.day06]]
+input = "bvwbjplbgvbhsrlpgdmjqwftvncz"
+part_2 = "23"
+
+[[2022.day15]]
+file = "day15_test.txt"
+params = { row = 10, bound = 20 }
+part_1 = "26"
+"#
+        .parse()
+        .unwrap();
+
+        let day06 = manifest.get(2022, "day06");
+        assert_eq!(2, day06.len());
+        assert!(day06[0].check(&Answers::new(7, 19)).is_ok());
+        assert!(day06[1].check(&Answers::new(7, 19)).is_err());
+
+        let day15 = &manifest.get(2022, "day15")[0];
+        assert_eq!(Params::from([("row", 10), ("bound", 20)]), day15.params);
+        assert!(day15.load(&input::default_dir(), 2022).is_ok());
+
+        assert_eq!(3, manifest.iter().count());
+        assert!(manifest.get(2021, "day01").is_empty());
+    }
+}
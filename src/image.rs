@@ -0,0 +1,438 @@
+//! Pictures of the states of solutions, written as PPM or PNG images without any external tools.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+use crate::viz::Colour;
+
+/// Maps values between 0 and 1 to colours, for drawing height and score maps.
+pub trait Palette {
+    fn colour(&self, t: f64) -> Colour;
+}
+
+impl<F: Fn(f64) -> Colour> Palette for F {
+    fn colour(&self, t: f64) -> Colour {
+        self(t)
+    }
+}
+
+/// A palette that blends evenly between its colours.
+#[derive(Debug, Clone, Copy)]
+pub struct Gradient(pub &'static [Colour]);
+
+impl Gradient {
+    pub const GREYSCALE: Self = Self(&[Colour::rgb(20, 20, 20), Colour::rgb(255, 255, 255)]);
+    pub const HEAT: Self = Self(&[
+        Colour::rgb(10, 10, 40),
+        Colour::rgb(120, 20, 120),
+        Colour::rgb(220, 60, 50),
+        Colour::rgb(240, 180, 40),
+        Colour::rgb(255, 255, 210),
+    ]);
+    pub const TERRAIN: Self = Self(&[
+        Colour::rgb(30, 70, 160),
+        Colour::rgb(60, 150, 80),
+        Colour::rgb(190, 180, 90),
+        Colour::rgb(130, 90, 60),
+        Colour::rgb(245, 245, 245),
+    ]);
+
+    /// The names of the built-in gradients, as accepted by [`Gradient::named`].
+    pub const NAMES: [&'static str; 3] = ["greyscale", "heat", "terrain"];
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "greyscale" => Some(Self::GREYSCALE),
+            "heat" => Some(Self::HEAT),
+            "terrain" => Some(Self::TERRAIN),
+            _ => None,
+        }
+    }
+}
+
+impl Palette for Gradient {
+    fn colour(&self, t: f64) -> Colour {
+        let stops = self.0;
+        let t = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let i = (t as usize).min(stops.len() - 2);
+
+        stops[i].mix(stops[i + 1], t - i as f64)
+    }
+}
+
+/// An RGB image, addressed with x increasing rightwards and y increasing downwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Colour) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// A map of `values`, given row by row, coloured by `palette` from the smallest value to the
+    /// largest, with missing values left black.
+    pub fn from_values(
+        width: usize,
+        height: usize,
+        values: &[Option<f64>],
+        palette: &dyn Palette,
+    ) -> Self {
+        let (min, max) = values
+            .iter()
+            .flatten()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        let range = if max > min { max - min } else { 1.0 };
+
+        let mut image = Self::new(width, height, Colour::BLACK);
+        for (pixel, value) in image.pixels.iter_mut().zip(values) {
+            if let Some(value) = value {
+                *pixel = palette.colour((value - min) / range);
+            }
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colours the pixel at `(x, y)`, if it's within the image.
+    pub fn set(&mut self, (x, y): (usize, usize), colour: Colour) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<Colour> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Copies `other` into the image with its top left at `(x, y)`.
+    pub fn paste(&mut self, other: &Image, (x, y): (usize, usize)) {
+        for (dy, row) in other.rows().enumerate() {
+            for (dx, &colour) in row.iter().enumerate() {
+                self.set((x + dx, y + dy), colour);
+            }
+        }
+    }
+
+    /// The image with each pixel drawn as a `factor` by `factor` square.
+    pub fn scale(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        let mut scaled = Self::new(self.width * factor, self.height * factor, Colour::BLACK);
+        for (y, row) in scaled.pixels.chunks_mut(scaled.width.max(1)).enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = self.pixels[y / factor * self.width + x / factor];
+            }
+        }
+
+        scaled
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Colour]> {
+        self.pixels.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Writes the image as a binary PPM.
+    pub fn write_ppm(&self, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for &Colour { r, g, b } in &self.pixels {
+            out.write_all(&[r, g, b])?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the image as an 8-bit RGB PNG, whose pixels are stored uncompressed.
+    pub fn write_png(&self, out: &mut dyn Write) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // A bit depth of 8, truecolour, and the only compression, filter and interlace methods
+        header.extend([8, 2, 0, 0, 0]);
+        write_chunk(out, b"IHDR", &header)?;
+
+        // Each row is preceded by its filter type, which is always none
+        let mut scanlines = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.rows() {
+            scanlines.push(0);
+            for &Colour { r, g, b } in row {
+                scanlines.extend([r, g, b]);
+            }
+        }
+        write_chunk(out, b"IDAT", &zlib_stored(&scanlines))?;
+
+        write_chunk(out, b"IEND", &[])
+    }
+
+    /// Saves the image as a PNG or a PPM, depending on the extension of `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let write = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => Self::write_png,
+            Some("ppm") => Self::write_ppm,
+            _ => bail!("{} isn't a .png or a .ppm", path.display()),
+        };
+
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        let mut out = BufWriter::new(file);
+        write(self, &mut out)?;
+        out.flush()?;
+
+        Ok(())
+    }
+}
+
+fn write_chunk(out: &mut dyn Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+
+    let crc = crc32(kind.iter().chain(data).copied());
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks, which is all a PNG needs to be
+/// valid.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let blocks = data.len().div_ceil(MAX_BLOCK).max(1);
+    let mut stream = Vec::with_capacity(2 + blocks * 5 + data.len() + 4);
+    // Deflate with a 32K window and no preset dictionary
+    stream.extend([0x78, 0x01]);
+
+    let mut chunks = data.chunks(MAX_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(chunk) = chunks.next() {
+        let last = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        stream.push(last as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(chunk);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(bytes: impl IntoIterator<Item = u8>) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    0xedb8_8320 ^ (crc >> 1)
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    !bytes.into_iter().fold(!0, |crc, byte| {
+        TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    // The most bytes that can be summed before `b` could overflow
+    const CHUNK: usize = 5552;
+
+    let (mut a, mut b) = (1, 0);
+    for chunk in bytes.chunks(CHUNK) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_their_check_values() {
+        assert_eq!(0xcbf4_3926, crc32(*b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+        assert_eq!(1, adler32(&[]));
+        // Enough bytes to overflow without reducing as it goes
+        assert_eq!(0xb623_eb2b, adler32(&[0xff; 10_000]));
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let mut image = Image::new(2, 1, Colour::BLACK);
+        image.set((1, 0), Colour::rgb(1, 2, 3));
+
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+
+        assert_eq!(b"P6\n2 1\n255\n\0\0\0\x01\x02\x03".as_slice(), out);
+    }
+
+    /// Reads the pixels back out of a PNG written by `write_png`, checking its chunks on the way.
+    fn read_png(png: &[u8]) -> (u32, u32, Vec<u8>) {
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+
+        let (mut size, mut stream) = ((0, 0), Vec::new());
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+            let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+            assert_eq!(crc32(kind.iter().chain(data).copied()), crc);
+
+            match kind {
+                b"IHDR" => {
+                    size = (
+                        u32::from_be_bytes(data[..4].try_into().unwrap()),
+                        u32::from_be_bytes(data[4..8].try_into().unwrap()),
+                    )
+                }
+                b"IDAT" => stream.extend(data),
+                _ => {}
+            }
+            rest = &rest[12 + len..];
+        }
+
+        let mut scanlines = Vec::new();
+        let mut blocks = &stream[2..];
+        loop {
+            let len = u16::from_le_bytes([blocks[1], blocks[2]]) as usize;
+            assert_eq!(!(len as u16), u16::from_le_bytes([blocks[3], blocks[4]]));
+            scanlines.extend(&blocks[5..5 + len]);
+            let last = blocks[0] == 1;
+            blocks = &blocks[5 + len..];
+            if last {
+                break;
+            }
+        }
+        assert_eq!(adler32(&scanlines).to_be_bytes(), blocks);
+
+        (size.0, size.1, scanlines)
+    }
+
+    #[test]
+    fn writes_png() {
+        let mut image = Image::new(3, 2, Colour::BLACK);
+        image.set((0, 0), Colour::rgb(255, 0, 0));
+        image.set((2, 1), Colour::rgb(0, 0, 255));
+
+        let mut out = Vec::new();
+        image.write_png(&mut out).unwrap();
+        let (width, height, scanlines) = read_png(&out);
+
+        assert_eq!((3, 2), (width, height));
+        #[rustfmt::skip]
+        let expected = vec![
+            0, 255, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 255,
+        ];
+        assert_eq!(expected, scanlines);
+    }
+
+    #[test]
+    fn splits_large_pngs_into_blocks() {
+        let image = Image::new(200, 200, Colour::WHITE);
+
+        let mut out = Vec::new();
+        image.write_png(&mut out).unwrap();
+        let (_, _, scanlines) = read_png(&out);
+
+        assert_eq!(200 * (1 + 3 * 200), scanlines.len());
+    }
+
+    #[test]
+    fn colours_values_by_palette() {
+        let image = Image::from_values(3, 1, &[Some(2.0), None, Some(4.0)], &Gradient::GREYSCALE);
+
+        assert_eq!(Some(Gradient::GREYSCALE.0[0]), image.get((0, 0)));
+        assert_eq!(Some(Colour::BLACK), image.get((1, 0)));
+        assert_eq!(Some(Gradient::GREYSCALE.0[1]), image.get((2, 0)));
+
+        // Any function of a value is a palette
+        let red = |t: f64| Colour::rgb((t * 255.0) as u8, 0, 0);
+        let image = Image::from_values(2, 1, &[Some(0.0), Some(1.0)], &red);
+        assert_eq!(Some(Colour::rgb(255, 0, 0)), image.get((1, 0)));
+    }
+
+    #[test]
+    fn scales_and_pastes() {
+        let mut image = Image::new(2, 1, Colour::BLACK);
+        image.set((1, 0), Colour::WHITE);
+
+        let scaled = image.scale(2);
+        assert_eq!((4, 2), (scaled.width(), scaled.height()));
+        assert_eq!(Some(Colour::WHITE), scaled.get((3, 1)));
+        assert_eq!(Some(Colour::BLACK), scaled.get((1, 1)));
+
+        let mut canvas = Image::new(3, 3, Colour::GREY);
+        canvas.paste(&image, (2, 2));
+        assert_eq!(Some(Colour::BLACK), canvas.get((2, 2)));
+        assert_eq!(Some(Colour::GREY), canvas.get((1, 2)));
+    }
+
+    #[test]
+    fn draws_every_image_on_its_example() {
+        let dir = crate::input::default_dir();
+        let examples = crate::examples::Manifest::load(&crate::examples::path(&dir)).unwrap();
+
+        for solution in crate::registry::SOLUTIONS {
+            let Some(draw) = solution.image else {
+                continue;
+            };
+            // day08's example isn't in the manifest, as its size is fixed at compile time
+            let (input, params) = match examples.get(solution.year, solution.name).first() {
+                Some(example) => (
+                    example.load(&dir, solution.year).unwrap(),
+                    example.params.clone(),
+                ),
+                None => {
+                    let path = crate::input::example_path(&dir, solution.year, solution.name);
+                    (crate::input::load(&path).unwrap(), Default::default())
+                }
+            };
+
+            let image = draw(&input, &params, &Gradient::HEAT).unwrap();
+
+            assert!(image.width() > 0 && image.height() > 0, "{}", solution.name);
+        }
+    }
+}
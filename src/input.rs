@@ -0,0 +1,110 @@
+//! Loading puzzle inputs at runtime rather than baking them in with `include_str!`.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+/// The `inputs` directory of this crate.
+pub fn default_dir() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"))
+}
+
+/// The path of the input for the solution `name` of `year` within `dir`, e.g.
+/// `inputs/2022/day08_par.txt`.
+pub fn path(dir: &Path, year: u16, name: &str) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{}.txt", name))
+}
+
+/// The path of the example input for the solution `name` of `year` within `dir`, e.g.
+/// `inputs/2022/day12_test.txt`.
+pub fn example_path(dir: &Path, year: u16, name: &str) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("{}_test.txt", name))
+}
+
+/// Loads and normalises the input at `path`.
+pub fn load(path: &Path) -> anyhow::Result<String> {
+    let input = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    Ok(normalise(&input))
+}
+
+/// Opens the input at `path` for streaming, where `-` is stdin. Unlike `load`, the input isn't
+/// normalised, so streaming solutions have to cope with untidy line endings themselves.
+pub fn open(path: &Path) -> anyhow::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Converts `\r\n` line endings to `\n`, strips trailing whitespace from every line and ends the
+/// input with exactly one newline, so that solutions see the same input whichever platform or editor
+/// it passed through.
+pub fn normalise(input: &str) -> String {
+    let mut normalised = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalised.push_str(line.trim_end());
+        normalised.push('\n');
+    }
+
+    let len = normalised.trim_end_matches('\n').len();
+    normalised.truncate(len);
+    normalised.push('\n');
+
+    normalised
+}
+
+/// The ways in which an input commonly gets mangled on its way to us, for testing that parsers
+/// cope with each of them.
+#[cfg(test)]
+pub(crate) fn variants(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let join = |line_ending: &str, trailing: &str, final_newline: bool| {
+        let mut joined = lines
+            .iter()
+            .map(|line| format!("{}{}", line, trailing))
+            .collect::<Vec<_>>()
+            .join(line_ending);
+        if final_newline {
+            joined.push_str(line_ending);
+        }
+        joined
+    };
+
+    vec![
+        join("\n", "", true),
+        join("\r\n", "", true),
+        join("\n", "", false),
+        join("\n", " \t", true),
+        join("\r\n", "  ", false),
+        // Trailing blank lines, as left by an editor or a copy and paste
+        join("\n", "", true) + "\n",
+        join("\r\n", "", true) + "  \r\n",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_every_variant_to_the_same_input() {
+        let input = "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n";
+
+        for variant in variants(input) {
+            assert_eq!(input, normalise(&variant));
+        }
+    }
+
+    #[test]
+    fn collapses_trailing_blank_lines() {
+        assert_eq!("1\n\n2\n", normalise("1\r\n\r\n2\r\n\r\n  \n"));
+    }
+}
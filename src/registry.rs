@@ -0,0 +1,306 @@
+//! A registry of every day's solution, so that runners can dispatch on a day chosen at runtime.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::BufRead;
+
+use anyhow::{anyhow, bail};
+use serde::Deserialize;
+
+use crate::image::{Image, Palette};
+use crate::viz::Animation;
+
+/// The answers to both parts of a day, formatted for display.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    pub part_1: String,
+    pub part_2: String,
+}
+
+impl Answers {
+    pub fn new(part_1: impl Display, part_2: impl Display) -> Self {
+        Self {
+            part_1: part_1.to_string(),
+            part_2: part_2.to_string(),
+        }
+    }
+}
+
+/// Named parameters of a puzzle that aren't part of its input, such as the row that day15 counts
+/// the covered positions of, which differ between the examples and the real puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The parameter `name`, or `default` if it isn't given.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str, default: T) -> anyhow::Result<T> {
+        match self.0.get(name) {
+            Some(&value) => {
+                T::try_from(value).map_err(|_| anyhow!("{} is out of range: {}", name, value))
+            }
+            None => Ok(default),
+        }
+    }
+}
+
+impl FromIterator<(String, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, i64)>>(params: I) -> Self {
+        Self(params.into_iter().collect())
+    }
+}
+
+impl<const N: usize> From<[(&str, i64); N]> for Params {
+    fn from(params: [(&str, i64); N]) -> Self {
+        Self(
+            params
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+}
+
+/// Plays a day's simulation on an input, with any params that the day takes.
+pub type Visualise = fn(&str, &Params, &mut Animation) -> anyhow::Result<()>;
+
+/// Draws a picture of a day's state on an input, colouring any map it shows with the palette.
+pub type Draw = fn(&str, &Params, &dyn Palette) -> anyhow::Result<Image>;
+
+pub struct Solution {
+    pub year: u16,
+    /// The name of the module, which is also the name of the input file, e.g. `day08_par`
+    pub name: &'static str,
+    pub day: u8,
+    pub solve: fn(&str) -> anyhow::Result<Answers>,
+    /// A variant that takes the puzzle's parameters, for days that have any
+    pub solve_with: Option<fn(&str, &Params) -> anyhow::Result<Answers>>,
+    /// A variant that reads its input incrementally, for days whose algorithms are single-pass
+    pub stream: Option<fn(&mut dyn BufRead) -> anyhow::Result<Answers>>,
+    /// Plays the day's simulation, for days that have one worth watching
+    pub viz: Option<Visualise>,
+    /// Draws the day's grid as an image, for days with one worth looking at
+    pub image: Option<Draw>,
+}
+
+impl Solution {
+    pub fn solve(&self, input: &str) -> anyhow::Result<Answers> {
+        (self.solve)(input)
+    }
+
+    /// Solves with `params` in place of the real puzzle's parameters.
+    pub fn solve_with(&self, input: &str, params: &Params) -> anyhow::Result<Answers> {
+        match self.solve_with {
+            Some(solve_with) => solve_with(input, params),
+            None if params.is_empty() => self.solve(input),
+            None => bail!("{} doesn't take any parameters", self.name),
+        }
+    }
+}
+
+macro_rules! answers {
+    ($year:ident::$day:ident::$f:ident) => {
+        |input| {
+            let (part_1, part_2) = crate::$year::$day::$f(input)?;
+            Ok(Answers::new(part_1, part_2))
+        }
+    };
+    ($year:ident::$day:ident::$f:ident, params) => {
+        |input, params| {
+            let (part_1, part_2) = crate::$year::$day::$f(input, params)?;
+            Ok(Answers::new(part_1, part_2))
+        }
+    };
+}
+
+/// Registers a day whose solution is its module's `run`, or `f` if it's given, along with any
+/// `params = f`, `stream = f`, `viz = f` and `image = f` variants.
+macro_rules! solution {
+    ($year:literal, $module:ident::$day:ident, $n:expr, $f:ident $(, $variant:ident = $g:ident)*) => {{
+        #[allow(unused_mut)]
+        let mut solution = Solution {
+            year: $year,
+            name: stringify!($day),
+            day: $n,
+            solve: answers!($module::$day::$f),
+            solve_with: None,
+            stream: None,
+            viz: None,
+            image: None,
+        };
+        $(solution!(@variant solution, $module::$day, $variant = $g);)*
+        solution
+    }};
+    ($year:literal, $module:ident::$day:ident, $n:expr $(, $variant:ident = $g:ident)*) => {
+        solution!($year, $module::$day, $n, run $(, $variant = $g)*)
+    };
+    (@variant $solution:ident, $module:ident::$day:ident, params = $f:ident) => {
+        $solution.solve_with = Some(answers!($module::$day::$f, params))
+    };
+    (@variant $solution:ident, $module:ident::$day:ident, stream = $f:ident) => {
+        $solution.stream = Some(answers!($module::$day::$f))
+    };
+    (@variant $solution:ident, $module:ident::$day:ident, viz = $f:ident) => {
+        $solution.viz = Some(crate::$module::$day::$f)
+    };
+    (@variant $solution:ident, $module:ident::$day:ident, image = $f:ident) => {
+        $solution.image = Some(crate::$module::$day::$f)
+    };
+}
+
+pub static SOLUTIONS: &[Solution] = &[
+    solution!(2022, y2022::day01, 1, stream = run_stream),
+    solution!(2022, y2022::day02, 2, stream = run_stream),
+    solution!(
+        2022,
+        y2022::day03,
+        3,
+        params = run_with,
+        stream = run_stream
+    ),
+    solution!(2022, y2022::day04, 4, stream = run_stream),
+    solution!(2022, y2022::day05, 5, params = run_with, viz = visualise),
+    solution!(2022, y2022::day06, 6, stream = run_stream),
+    solution!(2022, y2022::day07, 7),
+    solution!(2022, y2022::day08, 8, image = draw),
+    solution!(2022, y2022::day08_par, 8, params = run_with),
+    solution!(2022, y2022::day09, 9, viz = visualise),
+    solution!(2022, y2022::day10, 10, solve),
+    solution!(2022, y2022::day11, 11),
+    solution!(2022, y2022::day12, 12, params = run_with, image = draw),
+    solution!(2022, y2022::day13, 13),
+    solution!(2022, y2022::day14, 14, viz = visualise, image = draw),
+    solution!(2022, y2022::day15, 15, params = run_with),
+    solution!(2022, y2022::day16, 16),
+    solution!(2022, y2022::day17, 17, viz = visualise),
+    solution!(2022, y2022::day18, 18, image = draw),
+    solution!(2022, y2022::day19, 19),
+    solution!(2022, y2022::day20, 20, stream = run_stream),
+    solution!(2022, y2022::day21, 21),
+    solution!(2022, y2022::day22, 22, params = run_with),
+    solution!(2022, y2022::day23, 23, viz = visualise, image = draw),
+    solution!(2022, y2022::day24, 24, params = run_with, viz = visualise),
+    solution!(2022, y2022::day25, 25, stream = run_stream),
+];
+
+/// The most recent year with any solutions, which runners default to.
+pub fn latest_year() -> u16 {
+    SOLUTIONS
+        .iter()
+        .map(|solution| solution.year)
+        .max()
+        .unwrap()
+}
+
+/// Finds a solution of `year` by its name, e.g. `day08_par`, or by its day number, e.g. `8` or
+/// `08`, in which case the first solution for that day is returned.
+pub fn find(year: u16, query: &str) -> Option<&'static Solution> {
+    let mut solutions = SOLUTIONS.iter().filter(|solution| solution.year == year);
+
+    match query.parse::<u8>() {
+        Ok(day) => solutions.find(|solution| solution.day == day),
+        Err(_) => solutions.find(|solution| solution.name == query),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::BufReader;
+
+    #[test]
+    fn finds_solutions_by_name_and_number() {
+        assert_eq!(find(2022, "8").unwrap().name, "day08");
+        assert_eq!(find(2022, "08").unwrap().name, "day08");
+        assert_eq!(find(2022, "day08_par").unwrap().name, "day08_par");
+        assert!(find(2022, "26").is_none());
+        assert!(find(2022, "day26").is_none());
+        assert!(find(2015, "1").is_none());
+    }
+
+    #[test]
+    fn solves_through_the_registry() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let answers = find(2022, "day01").unwrap().solve(input).unwrap();
+
+        assert_eq!(answers, Answers::new(24000, 45000));
+    }
+
+    #[test]
+    fn only_passes_params_to_days_that_take_them() {
+        let params = Params::from([("row", 10)]);
+
+        assert_eq!(10, params.get("row", 2_000_000).unwrap());
+        assert_eq!(20, params.get("bound", 20).unwrap());
+        assert!(params.get::<u8>("row", 0).is_ok());
+        assert!(Params::from([("row", -1)]).get::<usize>("row", 0).is_err());
+
+        let input = "1000\n2000\n3000\n\n4000\n";
+        assert!(find(2022, "day01")
+            .unwrap()
+            .solve_with(input, &params)
+            .is_err());
+    }
+
+    /// Calls `f` with every example in the manifest, its solution and its loaded input.
+    fn for_each_example(mut f: impl FnMut(&Solution, &crate::examples::Example, &str)) {
+        let dir = crate::input::default_dir();
+        let manifest = crate::examples::Manifest::load(&crate::examples::path(&dir)).unwrap();
+
+        for (year, name, example) in manifest.iter() {
+            let solution = find(year, name).unwrap();
+            f(solution, example, &example.load(&dir, year).unwrap());
+        }
+    }
+
+    #[test]
+    fn streams_every_variant_of_the_examples() {
+        for_each_example(|solution, example, input| {
+            let Some(stream) = solution.stream.filter(|_| example.params.is_empty()) else {
+                return;
+            };
+            let answers = solution.solve(input).unwrap();
+
+            // Streams see the input as it is, without the normalisation that `input::load` does
+            for variant in crate::input::variants(input) {
+                // A tiny buffer makes sure that anything spanning refills is handled
+                for capacity in [3, 8 * 1024] {
+                    let mut reader = BufReader::with_capacity(capacity, variant.as_bytes());
+
+                    match stream(&mut reader) {
+                        Ok(streamed) => assert_eq!(answers, streamed, "{}", solution.name),
+                        Err(err) => panic!("{}: {:#} on {:?}", solution.name, err, variant),
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn streams_generated_inputs() {
+        for generator in crate::y2022::generate::GENERATORS {
+            let solution = find(2022, generator.name).unwrap();
+            let Some(stream) = solution.stream else {
+                continue;
+            };
+
+            for seed in 0..10 {
+                let input = generator.seeded(seed, generator.default_size.div_ceil(10));
+                let mut reader = BufReader::with_capacity(3, input.as_bytes());
+
+                assert_eq!(
+                    solution.solve(&input).unwrap(),
+                    stream(&mut reader).unwrap(),
+                    "{} seed {}",
+                    solution.name,
+                    seed
+                );
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::io::BufRead;
+
 use nom::character::complete::multispace0;
 use nom::error::ParseError;
 use nom::sequence::delimited;
@@ -5,10 +7,14 @@ use nom::IResult;
 
 #[macro_export]
 macro_rules! run {
-    ($day:tt) => {{
-        use aoc2022::$day::run;
-        let input = include_str!(std::concat!("../../inputs/", std::stringify!($day), ".txt"));
-        let (part_1, part_2) = run(input)?;
+    ($year:ident::$day:ident) => {{
+        use aoc2022::$year::$day::run;
+        // Year modules are named after their year, e.g. `y2022`
+        let year = std::stringify!($year)[1..].parse()?;
+        let path =
+            aoc2022::input::path(&aoc2022::input::default_dir(), year, std::stringify!($day));
+        let input = aoc2022::input::load(&path)?;
+        let (part_1, part_2) = run(&input)?;
 
         println!("Part 1: {}", part_1);
         println!("Part 2: {}", part_2);
@@ -19,11 +25,62 @@ macro_rules! run {
 
 // A combinator that takes a parser `inner` and produces a parser that also consumes both leading and
 // trailing whitespace, returning the output of `inner`.
-pub fn ws<'a, F: 'a, O, E: ParseError<&'a str>>(
+pub fn ws<'a, F, O, E: ParseError<&'a str>>(
     inner: F,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
 where
-    F: Fn(&'a str) -> IResult<&'a str, O, E>,
+    F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
 {
     delimited(multispace0, inner, multispace0)
 }
+
+/// Splits `input` into the sections separated by blank lines, tolerating `\r\n` line endings and
+/// whitespace on the blank lines themselves. Leading whitespace within a section is preserved.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(input[start..offset].trim_end());
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(input[start..].trim_end());
+    }
+
+    sections
+}
+
+/// Calls `f` with each line of `reader`, stripped of its line ending and trailing whitespace. A
+/// single buffer is reused so that memory use is bounded by the longest line.
+pub fn for_each_line<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(&str) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut line = String::new();
+    while reader.read_line(&mut line)? != 0 {
+        f(line.trim_end())?;
+        line.clear();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_tolerate_blank_line_variants() {
+        let input = "  a\r\nb\r\n \r\nc  \n\n\n\t\nd";
+
+        assert_eq!(vec!["  a\r\nb", "c", "d"], sections(input));
+    }
+}
@@ -0,0 +1,435 @@
+//! Drawing the states of simulations in the terminal, so that they can be watched as they run.
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(230, 230, 230);
+    pub const GREY: Self = Self::rgb(110, 110, 110);
+    pub const RED: Self = Self::rgb(220, 60, 50);
+    pub const ORANGE: Self = Self::rgb(240, 150, 40);
+    pub const YELLOW: Self = Self::rgb(240, 210, 80);
+    pub const GREEN: Self = Self::rgb(80, 200, 90);
+    pub const CYAN: Self = Self::rgb(80, 200, 220);
+    pub const BLUE: Self = Self::rgb(70, 110, 230);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// The colour `t` of the way from `self` to `other`.
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Self::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    const BLANK: Self = Self {
+        glyph: ' ',
+        colour: None,
+    };
+}
+
+/// A grid of cells covering part of a simulation, addressed by the simulation's own coordinates,
+/// with x increasing rightwards and y increasing downwards.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The coordinates of the top left cell
+    origin: (i64, i64),
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    /// The point to keep in view when the frame is cropped
+    focus: Option<(i64, i64)>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new(origin: (i64, i64), width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+            cells: vec![Cell::BLANK; width * height],
+            focus: None,
+            caption: String::new(),
+        }
+    }
+
+    /// A frame covering every point from `min` to `max` inclusive.
+    pub fn covering(min: (i64, i64), max: (i64, i64)) -> Self {
+        let width = (max.0 - min.0 + 1).max(0) as usize;
+        let height = (max.1 - min.1 + 1).max(0) as usize;
+
+        Self::new(min, width, height)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): (i64, i64)) -> Option<usize> {
+        let (x, y) = (x - self.origin.0, y - self.origin.1);
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+
+        Some(y as usize * self.width + x as usize)
+    }
+
+    /// Draws `glyph` at `point`, if it's within the frame.
+    pub fn set(&mut self, point: (i64, i64), glyph: char, colour: Option<Colour>) {
+        if let Some(i) = self.index(point) {
+            self.cells[i] = Cell { glyph, colour };
+        }
+    }
+
+    pub fn get(&self, point: (i64, i64)) -> Option<Cell> {
+        self.index(point).map(|i| self.cells[i])
+    }
+
+    pub fn set_focus(&mut self, point: (i64, i64)) {
+        self.focus = Some(point);
+    }
+
+    /// Sets the line shown beneath the frame, e.g. the round that it shows.
+    pub fn set_caption(&mut self, caption: impl Into<String>) {
+        self.caption = caption.into();
+    }
+
+    /// Crops the frame to at most `width` by `height` cells, centred on its focus if it has one and
+    /// otherwise keeping the top left.
+    pub fn crop(&self, width: usize, height: usize) -> Self {
+        let width = width.min(self.width);
+        let height = height.min(self.height);
+
+        let start = |origin: i64, len: usize, crop_len: usize, focus: Option<i64>| match focus {
+            Some(focus) => {
+                let start = focus - origin - crop_len as i64 / 2;
+                origin + start.clamp(0, (len - crop_len) as i64)
+            }
+            None => origin,
+        };
+        let origin = (
+            start(
+                self.origin.0,
+                self.width,
+                width,
+                self.focus.map(|focus| focus.0),
+            ),
+            start(
+                self.origin.1,
+                self.height,
+                height,
+                self.focus.map(|focus| focus.1),
+            ),
+        );
+
+        let mut cropped = Self::new(origin, width, height);
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                let point = (origin.0 + x, origin.1 + y);
+                cropped.cells[y as usize * width + x as usize] = self.get(point).unwrap();
+            }
+        }
+        cropped.focus = self.focus;
+        cropped.caption = self.caption.clone();
+
+        cropped
+    }
+
+    /// Writes the frame and its caption, colouring cells with ANSI escape codes if `ansi` is set.
+    pub fn write(&self, out: &mut dyn Write, ansi: bool) -> io::Result<()> {
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut current = None;
+            for cell in row {
+                if ansi && cell.colour != current {
+                    match cell.colour {
+                        Some(Colour { r, g, b }) => write!(out, "\x1b[38;2;{};{};{}m", r, g, b)?,
+                        None => write!(out, "\x1b[0m")?,
+                    }
+                    current = cell.colour;
+                }
+                write!(out, "{}", cell.glyph)?;
+            }
+            if ansi {
+                // Also clears whatever was left on the line by a wider frame
+                write!(out, "\x1b[0m\x1b[K")?;
+            }
+            writeln!(out)?;
+        }
+
+        if !self.caption.is_empty() {
+            writeln!(out, "{}", self.caption)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A state of a simulation that can be drawn.
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// How fast to play an animation.
+#[derive(Debug, Clone, Copy)]
+pub struct Speed {
+    /// Frames per second, where 0 is as fast as possible
+    pub fps: f64,
+    /// The number of steps of the simulation between frames
+    pub steps_per_frame: usize,
+}
+
+/// Plays simulations by drawing their states in place in the terminal.
+pub struct Animation<'a> {
+    out: &'a mut dyn Write,
+    speed: Speed,
+    /// The most cells to draw across and down, beyond which frames are cropped
+    viewport: (usize, usize),
+    /// Whether to draw in place and in colour, rather than writing frame after frame
+    ansi: bool,
+    frames: usize,
+    next_frame: Option<Instant>,
+    /// Whether the cursor has been hidden and still needs showing again
+    cursor_hidden: bool,
+}
+
+impl<'a> Animation<'a> {
+    pub fn new(out: &'a mut dyn Write, speed: Speed, viewport: (usize, usize), ansi: bool) -> Self {
+        Self {
+            out,
+            speed,
+            viewport,
+            ansi,
+            frames: 0,
+            next_frame: None,
+            cursor_hidden: false,
+        }
+    }
+
+    /// The number of frames drawn so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Draws `frame`, waiting until it's due.
+    pub fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Some(next_frame) = self.next_frame {
+            thread::sleep(next_frame.saturating_duration_since(Instant::now()));
+        }
+
+        let frame = frame.crop(self.viewport.0, self.viewport.1);
+        if self.ansi {
+            if self.frames == 0 {
+                // Clears the screen and hides the cursor
+                write!(self.out, "\x1b[2J\x1b[?25l")?;
+                self.cursor_hidden = true;
+            }
+            write!(self.out, "\x1b[H")?;
+            frame.write(self.out, true)?;
+            // Clears anything left below by a taller frame
+            write!(self.out, "\x1b[J")?;
+        } else {
+            frame.write(self.out, false)?;
+            writeln!(self.out)?;
+        }
+        self.out.flush()?;
+
+        self.frames += 1;
+        if self.speed.fps > 0.0 {
+            self.next_frame = Some(Instant::now() + Duration::from_secs_f64(1.0 / self.speed.fps));
+        }
+
+        Ok(())
+    }
+
+    /// Draws `state`, then advances it with `step` until that returns false, drawing it every
+    /// `steps_per_frame` steps and once more when it's finished.
+    pub fn play<S: Render>(
+        &mut self,
+        state: &mut S,
+        mut step: impl FnMut(&mut S) -> anyhow::Result<bool>,
+    ) -> anyhow::Result<()> {
+        self.draw(&state.render())?;
+
+        let mut steps = 0;
+        loop {
+            let running = step(state)?;
+            steps += 1;
+
+            if !running || steps % self.speed.steps_per_frame.max(1) == 0 {
+                self.draw(&state.render())?;
+            }
+            if !running {
+                break;
+            }
+        }
+
+        self.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        if self.cursor_hidden {
+            self.cursor_hidden = false;
+            write!(self.out, "\x1b[?25h")?;
+            self.out.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Animation<'_> {
+    /// Shows the cursor if a simulation stopped while it was hidden, e.g. by failing or panicking
+    /// part way through.
+    fn drop(&mut self) {
+        let _ = self.show_cursor();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_within_the_frame() {
+        let mut frame = Frame::covering((-1, -1), (1, 0));
+        frame.set((-1, -1), '#', None);
+        frame.set((1, 0), 'o', Some(Colour::YELLOW));
+        // Outside the frame
+        frame.set((2, 0), 'x', None);
+        frame.set_caption("round 1");
+
+        let mut out = Vec::new();
+        frame.write(&mut out, false).unwrap();
+        assert_eq!("#  \n  o\nround 1\n", String::from_utf8(out).unwrap());
+
+        let mut out = Vec::new();
+        frame.write(&mut out, true).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("\x1b[38;2;240;210;80mo\x1b[0m"));
+    }
+
+    #[test]
+    fn crops_around_the_focus() {
+        let mut frame = Frame::covering((0, 0), (9, 9));
+        frame.set((8, 1), '@', None);
+        frame.set_focus((8, 1));
+
+        let cropped = frame.crop(4, 4);
+
+        assert_eq!((4, 4), (cropped.width(), cropped.height()));
+        // The focus is as central as it can be without leaving the frame
+        assert_eq!(Some('@'), cropped.get((8, 1)).map(|cell| cell.glyph));
+        assert_eq!(None, cropped.get((5, 1)));
+        assert!(cropped.get((6, 0)).is_some());
+    }
+
+    struct Counter(usize);
+
+    impl Render for Counter {
+        fn render(&self) -> Frame {
+            let mut frame = Frame::new((0, 0), 1, 1);
+            frame.set((0, 0), char::from_digit(self.0 as u32, 10).unwrap(), None);
+            frame
+        }
+    }
+
+    #[test]
+    fn plays_every_nth_step() {
+        let mut out = Vec::new();
+        let speed = Speed {
+            fps: 0.0,
+            steps_per_frame: 2,
+        };
+        let mut animation = Animation::new(&mut out, speed, (80, 24), false);
+
+        animation
+            .play(&mut Counter(0), |counter| {
+                counter.0 += 1;
+                Ok(counter.0 < 5)
+            })
+            .unwrap();
+
+        // The initial state, every second step and the final state
+        assert_eq!(4, animation.frames());
+        drop(animation);
+        assert_eq!("0\n\n2\n\n4\n\n5\n\n", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn shows_the_cursor_after_failing() {
+        let mut out = Vec::new();
+        let speed = Speed {
+            fps: 0.0,
+            steps_per_frame: 1,
+        };
+        let mut animation = Animation::new(&mut out, speed, (80, 24), true);
+
+        let result = animation.play(&mut Counter(0), |counter| {
+            counter.0 += 1;
+            if counter.0 == 3 {
+                anyhow::bail!("stuck");
+            }
+            Ok(true)
+        });
+        drop(animation);
+
+        assert!(result.is_err());
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("\x1b[?25l"));
+        assert!(out.ends_with("\x1b[?25h"));
+    }
+
+    #[test]
+    fn plays_every_visualisation_on_its_example() {
+        let dir = crate::input::default_dir();
+        let examples = crate::examples::Manifest::load(&crate::examples::path(&dir)).unwrap();
+        let speed = Speed {
+            fps: 0.0,
+            steps_per_frame: 100,
+        };
+
+        for solution in crate::registry::SOLUTIONS {
+            let Some(viz) = solution.viz else {
+                continue;
+            };
+            let example = &examples.get(solution.year, solution.name)[0];
+            let input = example.load(&dir, solution.year).unwrap();
+
+            let mut out = Vec::new();
+            let mut animation = Animation::new(&mut out, speed, (80, 24), false);
+            viz(&input, &example.params, &mut animation).unwrap();
+
+            assert!(animation.frames() >= 2, "{}", solution.name);
+        }
+    }
+}
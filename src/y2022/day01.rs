@@ -0,0 +1,248 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use anyhow::bail;
+
+use crate::utils::for_each_line;
+
+pub fn run(input: &str) -> anyhow::Result<(u32, u32)> {
+    answers(&top_elves(input, 3, Mode::Strict)?)
+}
+
+/// Streaming variant of `run` that only keeps the three largest totals in memory
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(u32, u32)> {
+    let mut tally = Tally::new(3, Mode::Strict);
+    let mut number = 0;
+    for_each_line(reader, |line| {
+        number += 1;
+        tally.line(number, line);
+        Ok(())
+    })?;
+
+    answers(&tally.finish()?)
+}
+
+fn answers(top: &[(usize, u32)]) -> anyhow::Result<(u32, u32)> {
+    let most = top
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("there are no elves"))?;
+
+    let sum = top
+        .iter()
+        .try_fold(0u32, |sum, &(_, total)| sum.checked_add(total))
+        .ok_or_else(|| anyhow::anyhow!("sum of the top {} totals overflows", top.len()))?;
+
+    Ok((most.1, sum))
+}
+
+/// How to treat lines that aren't numbers of calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Reports every line that isn't a number of calories as an error
+    Strict,
+    /// Skips lines that aren't numbers of calories
+    Lenient,
+}
+
+/// The `k` elves carrying the most calories, as pairs of the elf's index in the input and their
+/// total, from the most calories to the least. Elves with equal totals are in input order.
+///
+/// A total that overflows is an error in either mode.
+pub fn top_elves(input: &str, k: usize, mode: Mode) -> anyhow::Result<Vec<(usize, u32)>> {
+    let mut tally = Tally::new(k, mode);
+    for (i, line) in input.lines().enumerate() {
+        tally.line(i + 1, line);
+    }
+
+    tally.finish()
+}
+
+/// Adds up each elf's calories a line at a time, keeping the top `k` elves and every malformed
+/// line.
+struct Tally {
+    mode: Mode,
+    top: TopK,
+    elf: usize,
+    /// The total of the current elf, if any of their lines have been seen yet
+    total: Option<u32>,
+    errors: Vec<String>,
+}
+
+impl Tally {
+    fn new(k: usize, mode: Mode) -> Self {
+        Self {
+            mode,
+            top: TopK::new(k),
+            elf: 0,
+            total: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Adds line `number`, counting from 1.
+    fn line(&mut self, number: usize, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            self.end_elf();
+            return;
+        }
+
+        let total = self.total.get_or_insert(0);
+        match line.parse::<u32>() {
+            Ok(calories) => match total.checked_add(calories) {
+                Some(sum) => *total = sum,
+                None => self.errors.push(format!(
+                    "line {} (elf {}): total calories overflow",
+                    number, self.elf
+                )),
+            },
+            Err(err) if self.mode == Mode::Strict => self.errors.push(format!(
+                "line {} (elf {}): invalid calories {:?}: {}",
+                number, self.elf, line, err
+            )),
+            Err(_) => {}
+        }
+    }
+
+    fn end_elf(&mut self) {
+        if let Some(total) = self.total.take() {
+            self.top.push(self.elf, total);
+            self.elf += 1;
+        }
+    }
+
+    fn finish(mut self) -> anyhow::Result<Vec<(usize, u32)>> {
+        self.end_elf();
+        if !self.errors.is_empty() {
+            bail!(
+                "{} malformed lines:\n{}",
+                self.errors.len(),
+                self.errors.join("\n")
+            );
+        }
+
+        Ok(self.top.into_sorted_vec())
+    }
+}
+
+/// The `k` largest totals seen so far, in a min-heap so that each push is O(log k).
+pub struct TopK {
+    k: usize,
+    /// Keyed so that the smallest total, and the later elf of equal totals, is evicted first
+    heap: BinaryHeap<Reverse<(u32, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: usize, total: u32) {
+        self.heap.push(Reverse((total, Reverse(elf))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elves and their totals, from the most calories to the least.
+    pub fn into_sorted_vec(self) -> Vec<(usize, u32)> {
+        // Sorting the reversed keys ascending puts the largest totals first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(elf)))| (elf, total))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let input = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+        let (part_1, part_2) = run(input).unwrap();
+        println!("{}", input);
+
+        assert_eq!(24000, part_1);
+        assert_eq!(45000, part_2);
+    }
+
+    #[test]
+    fn finds_the_top_elves() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+        assert_eq!(vec![(3, 24000)], top_elves(input, 1, Mode::Strict).unwrap());
+        assert_eq!(
+            vec![(3, 24000), (2, 11000), (4, 10000)],
+            top_elves(input, 3, Mode::Strict).unwrap()
+        );
+        assert_eq!(5, top_elves(input, 10, Mode::Strict).unwrap().len());
+        assert!(top_elves(input, 0, Mode::Strict).unwrap().is_empty());
+    }
+
+    #[test]
+    fn breaks_ties_by_input_order() {
+        let input = "5\n\n7\n\n5\n\n5";
+
+        assert_eq!(
+            vec![(1, 7), (0, 5), (2, 5)],
+            top_elves(input, 3, Mode::Strict).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_every_malformed_line() {
+        let input = "1000\nabc\n\n2000\n\n-5\n3000";
+
+        let err = run(input).unwrap_err().to_string();
+        assert!(err.starts_with("2 malformed lines"), "{}", err);
+        assert!(err.contains("line 2 (elf 0): invalid calories \"abc\""));
+        assert!(err.contains("line 6 (elf 2): invalid calories \"-5\""));
+        assert_eq!(err, run_stream(input.as_bytes()).unwrap_err().to_string());
+
+        assert_eq!(
+            vec![(2, 3000), (1, 2000), (0, 1000)],
+            top_elves(input, 3, Mode::Lenient).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let input = "4294967295\n\n4294967295\n1";
+
+        let err = top_elves(input, 1, Mode::Lenient).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("line 4 (elf 1): total calories overflow"));
+
+        // No elf's total overflows, but the sum of the top three does
+        let input = "4000000000\n\n4000000000\n";
+        assert_eq!(
+            "sum of the top 2 totals overflows",
+            run(input).unwrap_err().to_string()
+        );
+        let reader = std::io::BufReader::new(input.as_bytes());
+        assert!(run_stream(reader).is_err());
+    }
+}
@@ -0,0 +1,609 @@
+use std::fmt;
+use std::io::BufRead;
+
+use anyhow::{anyhow, bail};
+use itertools::Itertools;
+
+use crate::utils::for_each_line;
+
+pub fn run(input: &str) -> anyhow::Result<(i32, i32)> {
+    let game = Game::rock_paper_scissors();
+    let rounds = game.parse_guide(input)?;
+
+    let score_1 = rounds.iter().map(|&round| game.score_as_shape(round)).sum();
+    let score_2 = rounds
+        .iter()
+        .map(|&round| game.score_as_outcome(round))
+        .sum();
+
+    Ok((score_1, score_2))
+}
+
+/// Streaming variant of `run` that scores each round as it is read
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(i32, i32)> {
+    let game = Game::rock_paper_scissors();
+    let (mut score_1, mut score_2) = (0, 0);
+    let mut errors = Vec::new();
+    let mut number = 0;
+
+    for_each_line(reader, |line| {
+        number += 1;
+        match game.parse_line(number, line) {
+            Ok(Some(round)) => {
+                score_1 += game.score_as_shape(round);
+                score_2 += game.score_as_outcome(round);
+            }
+            Ok(None) => {}
+            Err(err) => errors.push(err),
+        }
+        Ok(())
+    })?;
+    check_errors(errors)?;
+
+    Ok((score_1, score_2))
+}
+
+fn check_errors(errors: Vec<String>) -> anyhow::Result<()> {
+    if !errors.is_empty() {
+        bail!("{} invalid rounds:\n{}", errors.len(), errors.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// A shape, given by its position in its game's dominance cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// The second column of the guide, given by its position among the game's second column letters,
+/// whose meaning depends on how the guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub them: Shape,
+    pub second: Column,
+}
+
+/// A game like Rock Paper Scissors with an odd number of shapes in a dominance cycle, where each
+/// shape beats the shapes an odd number of places before it in the cycle and loses to the rest, so
+/// that every shape beats exactly half of the others.
+///
+/// In a guide, the first column gives their shape as one of the first `n` letters from `A`, and the
+/// second column is one of the last `n` letters up to `Z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<String>,
+    shape_scores: Vec<i32>,
+    /// The scores of a loss, a draw and a win
+    outcome_scores: [i32; 3],
+}
+
+impl Game {
+    /// A game of the shapes in the order of their dominance cycle, where each shape scores one
+    /// more than the one before it, starting from 1, and a loss, draw and win score 0, 3 and 6.
+    pub fn new(shapes: &[&str]) -> anyhow::Result<Self> {
+        if shapes.len().is_multiple_of(2) || shapes.len() > 13 {
+            bail!(
+                "a game needs an odd number of shapes, at most 13, not {}",
+                shapes.len()
+            );
+        }
+
+        Ok(Self {
+            shapes: shapes.iter().map(|shape| shape.to_string()).collect(),
+            shape_scores: (1..=shapes.len() as i32).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).unwrap()
+    }
+
+    /// Sets the score of playing each shape, in the order of the cycle.
+    pub fn with_shape_scores(mut self, scores: &[i32]) -> anyhow::Result<Self> {
+        if scores.len() != self.shapes.len() {
+            bail!(
+                "expected a score for each of {} shapes, got {}",
+                self.shapes.len(),
+                scores.len()
+            );
+        }
+        self.shape_scores = scores.to_vec();
+
+        Ok(self)
+    }
+
+    /// Sets the scores of a loss, a draw and a win.
+    pub fn with_outcome_scores(mut self, scores: [i32; 3]) -> Self {
+        self.outcome_scores = scores;
+        self
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0]
+    }
+
+    /// The outcome of playing `me` against `them`.
+    pub fn outcome(&self, me: Shape, them: Shape) -> Outcome {
+        match self.distance(them, me) {
+            0 => Outcome::Draw,
+            d if d % 2 == 1 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// How many places after `from` in the cycle `to` is.
+    fn distance(&self, from: Shape, to: Shape) -> usize {
+        (to.0 + self.shapes.len() - from.0) % self.shapes.len()
+    }
+
+    /// How far `column` is from the middle letter of the second column, from `-n / 2` to `n / 2`.
+    fn offset(&self, column: Column) -> isize {
+        column.0 as isize - (self.shapes.len() / 2) as isize
+    }
+
+    /// The column read as the shape I play.
+    pub fn column_shape(&self, column: Column) -> Shape {
+        Shape(column.0)
+    }
+
+    /// The column read as the outcome I need, which is a loss before the middle letter, a draw at
+    /// it and a win after it.
+    pub fn column_outcome(&self, column: Column) -> Outcome {
+        match self.offset(column) {
+            0 => Outcome::Draw,
+            d if d > 0 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The shape to play against `them` when the column is read as the outcome I need. The further
+    /// the column is from the middle letter, the further around the cycle the shape is, so that
+    /// every letter picks a different shape.
+    pub fn column_play(&self, them: Shape, column: Column) -> Shape {
+        let n = self.shapes.len() as isize;
+        let d = self.offset(column);
+        // The winning shapes are an odd distance after theirs, and the losing ones before
+        let distance = match d {
+            0 => 0,
+            d if d > 0 => 2 * d - 1,
+            d => -(2 * -d - 1),
+        };
+
+        Shape((them.0 as isize + distance).rem_euclid(n) as usize)
+    }
+
+    /// My score for playing `me` against `them`.
+    pub fn score(&self, me: Shape, them: Shape) -> i32 {
+        self.shape_scores[me.0] + self.outcome_scores[self.outcome(me, them) as usize]
+    }
+
+    /// Scores a round where the second column is the shape I play
+    pub fn score_as_shape(&self, round: Round) -> i32 {
+        self.score(self.column_shape(round.second), round.them)
+    }
+
+    /// Scores a round where the second column is the outcome I need
+    pub fn score_as_outcome(&self, round: Round) -> i32 {
+        self.score(self.column_play(round.them, round.second), round.them)
+    }
+
+    /// Plays `me` against `them`.
+    pub fn play(&self, me: Shape, them: Shape) -> Play {
+        Play {
+            me,
+            outcome: self.outcome(me, them),
+            score: self.score(me, them),
+        }
+    }
+
+    /// Plays the round as the guide says to, reading it with `reading`.
+    pub fn play_round(&self, round: Round, reading: Reading) -> Play {
+        let me = match reading {
+            Reading::Shape => self.column_shape(round.second),
+            Reading::Outcome => self.column_play(round.them, round.second),
+        };
+
+        self.play(me, round.them)
+    }
+
+    /// The highest scoring play against `them`, which isn't always a win when the scores are
+    /// configured.
+    pub fn best_play(&self, them: Shape) -> Play {
+        self.shapes()
+            .map(|me| self.play(me, them))
+            .max_by_key(|play| play.score)
+            .unwrap()
+    }
+
+    /// Parses every round of the strategy guide, reporting every line that isn't a round.
+    pub fn parse_guide(&self, input: &str) -> anyhow::Result<Vec<Round>> {
+        let mut rounds = Vec::new();
+        let mut errors = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            match self.parse_line(i + 1, line) {
+                Ok(Some(round)) => rounds.push(round),
+                Ok(None) => {}
+                Err(err) => errors.push(err),
+            }
+        }
+        check_errors(errors)?;
+
+        Ok(rounds)
+    }
+
+    /// Parses line `number` of the guide, counting from 1, which is `None` if it's blank.
+    fn parse_line(&self, number: usize, line: &str) -> Result<Option<Round>, String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        self.parse_round(line)
+            .map(Some)
+            .map_err(|err| format!("line {}: {}", number, err))
+    }
+
+    pub fn parse_round(&self, s: &str) -> anyhow::Result<Round> {
+        let (them, second) = s
+            .split_once(' ')
+            .ok_or_else(|| anyhow!("expected two columns, got {:?}", s))?;
+
+        let n = self.shapes.len() as u8;
+        let them = parse_letter(them, b'A', b'A' + n - 1)?;
+        let second = parse_letter(second, b'Z' + 1 - n, b'Z')?;
+
+        Ok(Round {
+            them: Shape(them),
+            second: Column(second),
+        })
+    }
+}
+
+/// How to read the second column of the guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// As the shape I play, as in part 1
+    Shape,
+    /// As the outcome I need, as in part 2
+    Outcome,
+}
+
+/// A shape I played, and how it went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub me: Shape,
+    pub outcome: Outcome,
+    pub score: i32,
+}
+
+/// A round of the guide, played with each reading and as well as possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundAnalysis {
+    pub round: Round,
+    pub as_shape: Play,
+    pub as_outcome: Play,
+    pub best: Play,
+}
+
+impl RoundAnalysis {
+    pub fn play(&self, reading: Reading) -> Play {
+        match reading {
+            Reading::Shape => self.as_shape,
+            Reading::Outcome => self.as_outcome,
+        }
+    }
+}
+
+/// The wins, draws and losses of a guide and its total score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i32,
+}
+
+/// Compares the readings of a strategy guide with each other and with the best possible play. Its
+/// `Display` is a per-round breakdown.
+pub struct Analysis<'a> {
+    game: &'a Game,
+    pub rounds: Vec<RoundAnalysis>,
+}
+
+impl<'a> Analysis<'a> {
+    pub fn new(game: &'a Game, rounds: &[Round]) -> Self {
+        let rounds = rounds
+            .iter()
+            .map(|&round| RoundAnalysis {
+                round,
+                as_shape: game.play_round(round, Reading::Shape),
+                as_outcome: game.play_round(round, Reading::Outcome),
+                best: game.best_play(round.them),
+            })
+            .collect();
+
+        Self { game, rounds }
+    }
+
+    pub fn tally(&self, reading: Reading) -> Tally {
+        self.rounds
+            .iter()
+            .fold(Tally::default(), |mut tally, round| {
+                let play = round.play(reading);
+                match play.outcome {
+                    Outcome::Win => tally.wins += 1,
+                    Outcome::Draw => tally.draws += 1,
+                    Outcome::Loss => tally.losses += 1,
+                }
+                tally.score += play.score;
+                tally
+            })
+    }
+
+    /// The score of playing every round as well as possible.
+    pub fn best_score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.best.score).sum()
+    }
+
+    /// The fewest rounds to play as well as possible instead of as the guide says, when read with
+    /// `reading`, for the score to reach `target`, or `None` if it can't be reached. The rounds
+    /// are given by their index, in order.
+    pub fn changes_to_reach(&self, reading: Reading, target: i32) -> Option<Vec<usize>> {
+        // Changing the rounds that gain the most first changes the fewest
+        let gains = self
+            .rounds
+            .iter()
+            .enumerate()
+            .map(|(i, round)| (round.best.score - round.play(reading).score, i))
+            .filter(|&(gain, _)| gain > 0)
+            .sorted_by_key(|&(gain, i)| (-gain, i));
+
+        let mut score = self.tally(reading).score;
+        let mut changes = Vec::new();
+        for (gain, i) in gains {
+            if score >= target {
+                break;
+            }
+            score += gain;
+            changes.push(i);
+        }
+
+        (score >= target).then(|| changes.into_iter().sorted().collect())
+    }
+}
+
+impl fmt::Display for Analysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |shape| self.game.name(shape);
+
+        for (i, round) in self.rounds.iter().enumerate() {
+            write!(f, "{:>5}  against {:<8}", i + 1, name(round.round.them))?;
+            for (reading, play) in [
+                ("shape", round.as_shape),
+                ("outcome", round.as_outcome),
+                ("best", round.best),
+            ] {
+                write!(
+                    f,
+                    "  {}: {} {:?} {}",
+                    reading,
+                    name(play.me),
+                    play.outcome,
+                    play.score
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses one of the letters that a column can be, which are the letters from `first` to `last`,
+/// into its position among them.
+fn parse_letter(s: &str, first: u8, last: u8) -> anyhow::Result<usize> {
+    match *s.as_bytes() {
+        [letter] if (first..=last).contains(&letter) => Ok((letter - first) as usize),
+        _ => {
+            let letters: Vec<_> = (first..=last).map(|letter| letter as char).collect();
+            let (last, rest) = letters.split_last().unwrap();
+            bail!(
+                "expected {} or {}, got {:?}",
+                rest.iter().join(", "),
+                last,
+                s
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_GUIDE: &str = include_str!("../../inputs/2022/day02_test.txt");
+
+    #[test]
+    fn it_works() {
+        let input = "\
+A Y
+B X
+C Z";
+
+        let (part_1, part_2) = run(input).unwrap();
+
+        assert_eq!(15, part_1);
+        assert_eq!(12, part_2);
+    }
+
+    #[test]
+    fn parses_typed_rounds() {
+        let game = Game::rock_paper_scissors();
+        let round = game.parse_round("B Z").unwrap();
+
+        assert_eq!(
+            Round {
+                them: Shape(1),
+                second: Column(2)
+            },
+            round
+        );
+        assert_eq!("Scissors", game.name(game.column_shape(round.second)));
+        assert_eq!(Outcome::Win, game.column_outcome(round.second));
+    }
+
+    #[test]
+    fn plays_rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| {
+            game.shapes()
+                .find(|&shape| game.name(shape) == name)
+                .unwrap()
+        };
+
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert_eq!(Outcome::Win, game.outcome(shape(winner), shape(loser)));
+            assert_eq!(Outcome::Loss, game.outcome(shape(loser), shape(winner)));
+        }
+
+        // The five letters of the second column from V pick five different shapes, with outcomes
+        // that agree with the columns
+        for them in game.shapes() {
+            let plays: Vec<_> = (0..5)
+                .map(|column| game.column_play(them, Column(column)))
+                .collect();
+            assert_eq!(5, plays.iter().unique().count());
+            for (column, &me) in plays.iter().enumerate() {
+                assert_eq!(game.column_outcome(Column(column)), game.outcome(me, them));
+            }
+        }
+
+        let round = game.parse_round("E V").unwrap();
+        // Rock crushes Lizard, and V is the loss furthest from Lizard, to Paper
+        assert_eq!(1 + 6, game.score_as_shape(round));
+        assert_eq!(2, game.score_as_outcome(round));
+        assert!(game.parse_round("F V").is_err());
+        assert!(game.parse_round("A U").is_err());
+    }
+
+    #[test]
+    fn uses_configured_scores() {
+        let game = Game::rock_paper_scissors()
+            .with_shape_scores(&[10, 20, 30])
+            .unwrap()
+            .with_outcome_scores([-1, 0, 1]);
+        let rounds = game.parse_guide("A Y\nB X\nC Z").unwrap();
+
+        let score: i32 = rounds.iter().map(|&round| game.score_as_shape(round)).sum();
+        assert_eq!(20 + 1 + 10 - 1 + 30, score);
+        assert!(Game::rock_paper_scissors().with_shape_scores(&[1]).is_err());
+        assert!(Game::new(&["Rock", "Paper"]).is_err());
+    }
+
+    #[test]
+    fn analyses_guides() {
+        let game = Game::rock_paper_scissors();
+        let rounds = game.parse_guide(TEST_GUIDE).unwrap();
+        let analysis = Analysis::new(&game, &rounds);
+
+        assert_eq!(
+            Tally {
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                score: 15
+            },
+            analysis.tally(Reading::Shape)
+        );
+        assert_eq!(
+            Tally {
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                score: 12
+            },
+            analysis.tally(Reading::Outcome)
+        );
+        // Paper, Scissors and Rock win every round
+        assert_eq!(8 + 9 + 7, analysis.best_score());
+        assert_eq!(
+            Play {
+                me: Shape(0),
+                outcome: Outcome::Loss,
+                score: 1
+            },
+            analysis.rounds[1].as_shape
+        );
+
+        let breakdown = analysis.to_string();
+        assert_eq!(3, breakdown.lines().count());
+        assert!(breakdown
+            .lines()
+            .next()
+            .unwrap()
+            .contains("shape: Paper Win 8"));
+    }
+
+    #[test]
+    fn finds_the_fewest_changes_to_reach_a_target() {
+        let game = Game::rock_paper_scissors();
+        let rounds = game.parse_guide(TEST_GUIDE).unwrap();
+        let analysis = Analysis::new(&game, &rounds);
+
+        // Changing the loss to a win gains 8, and the draw on the third round gains 1
+        assert_eq!(Some(vec![]), analysis.changes_to_reach(Reading::Shape, 15));
+        assert_eq!(Some(vec![1]), analysis.changes_to_reach(Reading::Shape, 20));
+        assert_eq!(
+            Some(vec![1, 2]),
+            analysis.changes_to_reach(Reading::Shape, 24)
+        );
+        assert_eq!(None, analysis.changes_to_reach(Reading::Shape, 25));
+        assert_eq!(
+            Some(vec![0, 1]),
+            analysis.changes_to_reach(Reading::Outcome, 24)
+        );
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let input = "A Y\nD X\n\nC Z\nB  X\nAY";
+
+        let err = run(input).unwrap_err().to_string();
+        assert!(err.starts_with("3 invalid rounds"), "{}", err);
+        assert!(err.contains("line 2: expected A, B or C, got \"D\""));
+        assert!(err.contains("line 5: expected X, Y or Z, got \" X\""));
+        assert!(err.contains("line 6: expected two columns, got \"AY\""));
+        assert_eq!(err, run_stream(input.as_bytes()).unwrap_err().to_string());
+    }
+}
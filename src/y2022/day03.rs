@@ -0,0 +1,419 @@
+use std::io::BufRead;
+
+use anyhow::bail;
+
+use crate::registry::Params;
+use crate::utils::for_each_line;
+
+pub fn run(input: &str) -> anyhow::Result<(u32, u32)> {
+    run_with(input, &Params::default())
+}
+
+/// Solves with badges shared by groups of `group` elves, which defaults to 3.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(u32, u32)> {
+    let group_size = params.get("group", 3)?;
+
+    match bulk_priorities(input, group_size) {
+        Some(priorities) => Ok(priorities),
+        // Only the slower path says what's wrong with the input
+        None => Ok(shared_items(input, group_size)?.priorities()),
+    }
+}
+
+/// Streaming variant of `run` that folds each group's mask as its rucksacks are read
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(u32, u32)> {
+    let (mut part_1, mut part_2) = (0, 0);
+    let mut checker = Checker::new(3)?;
+    let mut number = 0;
+
+    for_each_line(reader, |line| {
+        number += 1;
+        let (rucksack, group) = checker.line(number, line);
+        part_1 += rucksack.map_or(0, |shared| shared.priority);
+        part_2 += group.map_or(0, |shared| shared.priority);
+        Ok(())
+    })?;
+    checker.finish()?;
+
+    Ok((part_1, part_2))
+}
+
+/// The item shared by a rucksack's compartments, or by a group's rucksacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shared {
+    /// The line of the rucksack, or of the first rucksack of the group, counting from 1
+    pub line: usize,
+    pub item: char,
+    pub priority: u32,
+}
+
+/// The items shared by every rucksack and every group, in input order.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub rucksacks: Vec<Shared>,
+    pub groups: Vec<Shared>,
+}
+
+impl Report {
+    /// The sums of the priorities of the rucksacks' items and of the groups' badges.
+    pub fn priorities(&self) -> (u32, u32) {
+        let sum = |shared: &[Shared]| shared.iter().map(|shared| shared.priority).sum();
+
+        (sum(&self.rucksacks), sum(&self.groups))
+    }
+}
+
+/// Finds the item shared by each rucksack's compartments and the badge shared by each group of
+/// `group_size` rucksacks, reporting every rucksack or group that doesn't share exactly one item.
+pub fn shared_items(input: &str, group_size: usize) -> anyhow::Result<Report> {
+    let mut report = Report::default();
+    let mut checker = Checker::new(group_size)?;
+
+    for (i, line) in input.lines().enumerate() {
+        let (rucksack, group) = checker.line(i + 1, line);
+        report.rucksacks.extend(rucksack);
+        report.groups.extend(group);
+    }
+    checker.finish()?;
+
+    Ok(report)
+}
+
+/// The mask of every byte, so that the mask of a compartment takes a lookup per item rather than a
+/// couple of comparisons and a shift.
+static MASKS: [u64; 256] = {
+    let mut masks = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        masks[byte] = ascii_letter_to_mask(byte as u8);
+        byte += 1;
+    }
+    masks
+};
+
+/// The sums of the priorities of the rucksacks' items and of the groups' badges, working over the
+/// whole input without building any rucksacks. It's `None` if any rucksack or group doesn't share
+/// exactly one item, or the last group is incomplete, in which case `shared_items` reports why.
+pub fn bulk_priorities(input: &str, group_size: usize) -> Option<(u32, u32)> {
+    if group_size == 0 {
+        return None;
+    }
+
+    let (mut part_1, mut part_2) = (0, 0);
+    let mut group = u64::MAX;
+    let mut in_group = 0;
+    // Any mask without exactly one item marks the input as invalid
+    let mut valid = true;
+
+    for line in input.as_bytes().split(|&byte| byte == b'\n') {
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (left, right) = line.split_at(line.len() / 2);
+        let (left, right) = (bulk_mask(left), bulk_mask(right));
+        let shared = left & right;
+        valid &= shared.count_ones() == 1;
+        part_1 += mask_to_priority(shared);
+
+        group &= left | right;
+        in_group += 1;
+        if in_group == group_size {
+            valid &= group.count_ones() == 1;
+            part_2 += mask_to_priority(group);
+            group = u64::MAX;
+            in_group = 0;
+        }
+    }
+
+    (valid && in_group == 0).then_some((part_1, part_2))
+}
+
+/// The mask of every item in `bytes`, folded into eight independent masks so that the lookups
+/// don't wait on each other and the folds can be vectorised.
+fn bulk_mask(bytes: &[u8]) -> u64 {
+    let mut masks = [0; 8];
+    let chunks = bytes.chunks_exact(8);
+    let rest = chunks.remainder();
+
+    for chunk in chunks {
+        for (mask, &byte) in masks.iter_mut().zip(chunk) {
+            *mask |= MASKS[byte as usize];
+        }
+    }
+
+    rest.iter().fold(
+        masks.iter().fold(0, |acc, mask| acc | mask),
+        |acc, &byte| acc | MASKS[byte as usize],
+    )
+}
+
+/// Checks rucksacks a line at a time, keeping the mask of the current group and every error.
+struct Checker {
+    group_size: usize,
+    group: u64,
+    /// The line of the group's first rucksack
+    group_start: usize,
+    in_group: usize,
+    errors: Vec<String>,
+}
+
+impl Checker {
+    fn new(group_size: usize) -> anyhow::Result<Self> {
+        if group_size == 0 {
+            bail!("groups need at least one elf");
+        }
+
+        Ok(Self {
+            group_size,
+            group: u64::MAX,
+            group_start: 0,
+            in_group: 0,
+            errors: Vec::new(),
+        })
+    }
+
+    /// Checks the rucksack on line `number`, counting from 1, returning the item shared by its
+    /// compartments and, if it completes a group, the group's badge. Blank lines are skipped.
+    fn line(&mut self, number: usize, line: &str) -> (Option<Shared>, Option<Shared>) {
+        let line = line.trim();
+        if line.is_empty() {
+            return (None, None);
+        }
+
+        let rucksack = Rucksack::from_line(line);
+        let shared = self.check(number, rucksack.intersect(), || {
+            format!("line {}: rucksack", number)
+        });
+
+        if self.in_group == 0 {
+            self.group_start = number;
+        }
+        self.group &= rucksack.union();
+        self.in_group += 1;
+
+        let mut badge = None;
+        if self.in_group == self.group_size {
+            let (start, group) = (self.group_start, self.group);
+            badge = self.check(start, group, || {
+                format!("lines {} to {}: group", start, number)
+            });
+            self.group = u64::MAX;
+            self.in_group = 0;
+        }
+
+        (shared, badge)
+    }
+
+    /// The item in `mask`, or `None` after recording an error for `what` if there isn't exactly
+    /// one.
+    fn check(&mut self, line: usize, mask: u64, what: impl Fn() -> String) -> Option<Shared> {
+        match mask.count_ones() {
+            1 => Some(Shared {
+                line,
+                item: mask_to_item(mask),
+                priority: mask_to_priority(mask),
+            }),
+            0 => {
+                self.errors.push(format!("{} shares no items", what()));
+                None
+            }
+            n => {
+                let items: String = (0..64)
+                    .map(|bit| mask & (1 << bit))
+                    .filter(|&bit| bit != 0)
+                    .map(mask_to_item)
+                    .collect();
+                self.errors
+                    .push(format!("{} shares {} items: {}", what(), n, items));
+                None
+            }
+        }
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        let mut errors = self.errors;
+        if self.in_group != 0 {
+            errors.push(format!(
+                "line {}: group of {} rucksacks is incomplete, expected {}",
+                self.group_start, self.in_group, self.group_size
+            ));
+        }
+        if !errors.is_empty() {
+            bail!("{} invalid rucksacks:\n{}", errors.len(), errors.join("\n"));
+        }
+
+        Ok(())
+    }
+}
+
+const fn ascii_letter_to_mask(byte: u8) -> u64 {
+    if byte.is_ascii_lowercase() {
+        1 << (byte - b'a')
+    } else if byte.is_ascii_uppercase() {
+        1 << (byte - b'A' + 26)
+    } else {
+        0
+    }
+}
+
+const fn mask_to_priority(mask: u64) -> u32 {
+    64 - u64::leading_zeros(mask)
+}
+
+/// The item of the highest priority in `mask`.
+fn mask_to_item(mask: u64) -> char {
+    match mask_to_priority(mask) as u8 {
+        priority @ 1..=26 => (b'a' + priority - 1) as char,
+        priority => (b'A' + priority - 27) as char,
+    }
+}
+
+struct Rucksack {
+    left: u64,
+    right: u64,
+}
+
+impl Rucksack {
+    fn from_line(line: &str) -> Self {
+        let bytes = line.as_bytes();
+        let (left, right) = bytes.split_at(bytes.len() / 2);
+
+        let mask_fold = |acc: u64, &byte: &u8| acc | ascii_letter_to_mask(byte);
+
+        let left = left.iter().fold(0, mask_fold);
+        let right = right.iter().fold(0, mask_fold);
+
+        Self { left, right }
+    }
+
+    fn intersect(&self) -> u64 {
+        self.left & self.right
+    }
+
+    fn union(&self) -> u64 {
+        self.left | self.right
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2022/day03_test.txt");
+
+    #[test]
+    fn ascii_letter_to_mask_works() {
+        assert_eq!(1 << 0, ascii_letter_to_mask(b'a'));
+        assert_eq!(1 << 25, ascii_letter_to_mask(b'z'));
+        assert_eq!(1 << 26, ascii_letter_to_mask(b'A'));
+        assert_eq!(1 << 51, ascii_letter_to_mask(b'Z'));
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!((157, 70), run(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn reports_shared_items() {
+        let report = shared_items(TEST_INPUT, 3).unwrap();
+
+        let items: String = report.rucksacks.iter().map(|shared| shared.item).collect();
+        assert_eq!("pLPvts", items);
+        assert_eq!(
+            vec![
+                Shared {
+                    line: 1,
+                    item: 'r',
+                    priority: 18
+                },
+                Shared {
+                    line: 4,
+                    item: 'Z',
+                    priority: 52
+                }
+            ],
+            report.groups
+        );
+    }
+
+    #[test]
+    fn groups_by_any_size() {
+        let input = "aBcB\ndeae\nFgFh\nhxyh";
+
+        let report = shared_items(input, 2).unwrap();
+        let badges: Vec<_> = report
+            .groups
+            .iter()
+            .map(|shared| (shared.line, shared.item))
+            .collect();
+        assert_eq!(vec![(1, 'a'), (3, 'h')], badges);
+        assert_eq!((28 + 5 + 32 + 8, 1 + 8), report.priorities());
+        assert_eq!(
+            (28 + 5 + 32 + 8, 1 + 8),
+            run_with(input, &Params::from([("group", 2)])).unwrap()
+        );
+
+        let err = shared_items(input, 4).unwrap_err().to_string();
+        assert!(
+            err.contains("lines 1 to 4: group shares no items"),
+            "{}",
+            err
+        );
+        assert!(shared_items(input, 0).is_err());
+    }
+
+    #[test]
+    fn reports_rucksacks_without_exactly_one_shared_item() {
+        let input = "abcd\nabab\naAbA\nAa";
+
+        let err = shared_items(input, 2).unwrap_err().to_string();
+        assert!(err.starts_with("5 invalid rucksacks"), "{}", err);
+        assert!(err.contains("line 1: rucksack shares no items"));
+        assert!(err.contains("line 2: rucksack shares 2 items: ab"));
+        assert!(err.contains("lines 1 to 2: group shares 2 items: ab"));
+        assert!(err.contains("lines 3 to 4: group shares 2 items: aA"));
+        assert!(err.contains("line 4: rucksack shares no items"));
+
+        let err = shared_items("aa\naa\naa\naa", 3).unwrap_err().to_string();
+        assert!(err.contains("line 4: group of 1 rucksacks is incomplete, expected 3"));
+    }
+
+    #[test]
+    fn bulk_agrees_with_shared_items() {
+        let generator = crate::y2022::generate::find("day03").unwrap();
+        for seed in 0..10 {
+            let input = generator.seeded(seed, 300);
+
+            assert_eq!(
+                shared_items(&input, 3).unwrap().priorities(),
+                bulk_priorities(&input, 3).unwrap()
+            );
+        }
+
+        assert_eq!(Some((157, 70)), bulk_priorities(TEST_INPUT, 3));
+        assert_eq!(Some((73, 9)), bulk_priorities("aBcB\ndeae\nFgFh\nhxyh", 2));
+        // Compartments longer than eight items go through the unrolled fold
+        let line = "bcdefghijklmnopqaArstuvwxyzABCDEFGa";
+        let rucksack = Rucksack::from_line(line);
+        let (left, right) = line.as_bytes().split_at(line.len() / 2);
+        assert_eq!(rucksack.left, bulk_mask(left));
+        assert_eq!(rucksack.right, bulk_mask(right));
+    }
+
+    #[test]
+    fn bulk_rejects_what_shared_items_reports() {
+        for (input, group_size) in [
+            ("abcd\nabab\naAbA\nAa", 2),
+            ("aBcB\ndeae\nFgFh\nhxyh", 4),
+            ("aa\naa\naa\naa", 3),
+            ("aa", 0),
+        ] {
+            assert_eq!(None, bulk_priorities(input, group_size));
+            assert!(shared_items(input, group_size).is_err());
+            assert!(run_with(input, &Params::from([("group", group_size as i64)])).is_err());
+        }
+    }
+}
@@ -0,0 +1,523 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending, u8};
+use nom::combinator::{all_consuming, eof, map, opt, verify};
+use nom::multi::many0;
+use nom::sequence::{separated_pair, terminated};
+use nom::IResult;
+
+use crate::utils::for_each_line;
+
+pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
+    let relations: Vec<_> = parse_pairs(input)?
+        .into_iter()
+        .map(|(a, b)| a.relation(b))
+        .collect();
+
+    Ok((part_1(&relations), part_2(&relations)))
+}
+
+/// Streaming variant of `run` that classifies each pair as it is read
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(usize, usize)> {
+    let (mut part_1, mut part_2) = (0, 0);
+
+    for_each_line(reader, |line| {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let (_, (a, b)) =
+            all_consuming(parse_pair)(line).map_err(|_| anyhow::anyhow!("Parse error!"))?;
+        let relation = a.relation(b);
+        part_1 += usize::from(relation.is_containment());
+        part_2 += usize::from(relation.shares_sections());
+        Ok(())
+    })?;
+
+    Ok((part_1, part_2))
+}
+
+fn part_1(relations: &[Relation]) -> usize {
+    relations
+        .iter()
+        .filter(|relation| relation.is_containment())
+        .count()
+}
+
+fn part_2(relations: &[Relation]) -> usize {
+    relations
+        .iter()
+        .filter(|relation| relation.shares_sections())
+        .count()
+}
+
+/// The integer types that sections can be numbered with.
+pub trait Section: Copy + Ord {
+    /// The next section, unless this is the last one that the type can number.
+    fn next(self) -> Option<Self>;
+
+    /// The previous section, unless this is the first one that the type can number.
+    fn prev(self) -> Option<Self>;
+}
+
+macro_rules! section {
+    ($($t:ty),*) => {
+        $(impl Section for $t {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        })*
+    };
+}
+
+section!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The sections from `start` to `end` inclusive that an elf is assigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// How one assignment relates to another, as one of Allen's 13 interval relations. As sections are
+/// whole numbers, an assignment meets another when the other starts at the section after it ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// Ends at least a section before the other starts
+    Before,
+    /// Ends the section before the other starts
+    Meets,
+    /// Starts before the other and ends within it
+    Overlaps,
+    /// Starts with the other and ends before it
+    Starts,
+    /// Starts after the other and ends before it
+    During,
+    /// Starts after the other and ends with it
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Self; 13] = [
+        Self::Before,
+        Self::Meets,
+        Self::Overlaps,
+        Self::Starts,
+        Self::During,
+        Self::Finishes,
+        Self::Equals,
+        Self::FinishedBy,
+        Self::Contains,
+        Self::StartedBy,
+        Self::OverlappedBy,
+        Self::MetBy,
+        Self::After,
+    ];
+
+    /// The relation of the other assignment to this one.
+    pub fn inverse(self) -> Self {
+        Self::ALL[Self::ALL.len() - 1 - self as usize]
+    }
+
+    /// Whether either assignment contains the whole of the other.
+    pub fn is_containment(self) -> bool {
+        matches!(
+            self,
+            Self::Starts
+                | Self::During
+                | Self::Finishes
+                | Self::Equals
+                | Self::FinishedBy
+                | Self::Contains
+                | Self::StartedBy
+        )
+    }
+
+    /// Whether the assignments have any sections in common.
+    pub fn shares_sections(self) -> bool {
+        !matches!(self, Self::Before | Self::Meets | Self::MetBy | Self::After)
+    }
+}
+
+impl<T: Section> Assignment<T> {
+    /// The assignment from `start` to `end`, unless it ends before it starts.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// How this assignment relates to `other`.
+    pub fn relation(self, other: Self) -> Relation {
+        use Ordering::*;
+
+        if self.end < other.start {
+            return if self.end.next() == Some(other.start) {
+                Relation::Meets
+            } else {
+                Relation::Before
+            };
+        }
+        if other.end < self.start {
+            return if other.end.next() == Some(self.start) {
+                Relation::MetBy
+            } else {
+                Relation::After
+            };
+        }
+
+        match (self.start.cmp(&other.start), self.end.cmp(&other.end)) {
+            (Less, Less) => Relation::Overlaps,
+            (Equal, Less) => Relation::Starts,
+            (Greater, Less) => Relation::During,
+            (Greater, Equal) => Relation::Finishes,
+            (Equal, Equal) => Relation::Equals,
+            (Less, Equal) => Relation::FinishedBy,
+            (Less, Greater) => Relation::Contains,
+            (Equal, Greater) => Relation::StartedBy,
+            (Greater, Greater) => Relation::OverlappedBy,
+        }
+    }
+}
+
+/// Every elf's assignment, two to a line, so that an elf's index is twice its line's index plus
+/// one for the second elf of the pair.
+pub fn assignments(input: &str) -> anyhow::Result<Vec<Assignment<u8>>> {
+    Ok(parse_pairs(input)?
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+        .collect())
+}
+
+/// How many elves are assigned each section, as the runs of sections with the same number of
+/// elves, in order. Sections without any elves are left out.
+pub fn coverage<T: Section>(assignments: &[Assignment<T>]) -> Vec<(Assignment<T>, usize)> {
+    // Ends come after starts at the same section, as assignments include their end
+    let mut events: Vec<_> = assignments
+        .iter()
+        .flat_map(|assignment| [(assignment.start, false), (assignment.end, true)])
+        .collect();
+    events.sort_unstable();
+
+    let mut runs: Vec<(Assignment<T>, usize)> = Vec::new();
+    let mut push = |start: T, end: T, count: usize| match runs.last_mut() {
+        Some((last, last_count)) if *last_count == count && last.end.next() == Some(start) => {
+            last.end = end;
+        }
+        _ => runs.push((Assignment { start, end }, count)),
+    };
+
+    let mut count = 0;
+    // The first section that hasn't been added to a run yet, while any elves are assigned it
+    let mut run_start = None;
+    for (section, is_end) in events {
+        if is_end {
+            if let Some(start) = run_start.filter(|&start| start <= section) {
+                push(start, section, count);
+                run_start = section.next();
+            }
+            count -= 1;
+        } else {
+            if let Some(start) = run_start.filter(|&start| count > 0 && start < section) {
+                // Safe to unwrap as the section is after the start of the run
+                push(start, section.prev().unwrap(), count);
+            }
+            run_start = Some(section);
+            count += 1;
+        }
+    }
+
+    runs
+}
+
+/// The most elves assigned any one section, and the runs of sections assigned that many.
+pub fn most_covered<T: Section>(assignments: &[Assignment<T>]) -> (usize, Vec<Assignment<T>>) {
+    let runs = coverage(assignments);
+    let most = runs.iter().map(|&(_, count)| count).max().unwrap_or(0);
+
+    let sections = runs
+        .into_iter()
+        .filter(|&(_, count)| count == most)
+        .map(|(run, _)| run)
+        .collect();
+
+    (most, sections)
+}
+
+/// Every pair of elves whose assignments share any sections, as their indices with the smaller
+/// first, in O(n log n + k) for `k` pairs.
+pub fn overlapping_pairs<T: Section>(assignments: &[Assignment<T>]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..assignments.len()).collect();
+    order.sort_unstable_by_key(|&i| assignments[i].start);
+
+    // The assignments that started before the current one, with whichever ends first on top
+    let mut active = BinaryHeap::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        let assignment = assignments[i];
+        while let Some(&Reverse((end, _))) = active.peek() {
+            if end >= assignment.start {
+                break;
+            }
+            active.pop();
+        }
+
+        // Everything still active ends at or after the start of this one, so overlaps it
+        pairs.extend(
+            active
+                .iter()
+                .map(|&Reverse((_, j)): &Reverse<(T, usize)>| (i.min(j), i.max(j))),
+        );
+        active.push(Reverse((assignment.end, i)));
+    }
+    pairs.sort_unstable();
+
+    pairs
+}
+
+/// The elves whose assignments share any sections with anyone else's, in O(n log n) however many
+/// pairs overlap.
+pub fn overlapping_elves<T: Section>(assignments: &[Assignment<T>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..assignments.len()).collect();
+    order.sort_unstable_by_key(|&i| assignments[i].start);
+
+    let mut overlapping = vec![false; assignments.len()];
+    let mut furthest_end: Option<T> = None;
+    for (position, &i) in order.iter().enumerate() {
+        let assignment = assignments[i];
+        // An earlier assignment overlaps this one if any of them reach its start, and a later
+        // one does if the next to start starts within it
+        let overlaps_earlier = furthest_end.is_some_and(|end| end >= assignment.start);
+        let overlaps_later = order
+            .get(position + 1)
+            .is_some_and(|&next| assignments[next].start <= assignment.end);
+        overlapping[i] = overlaps_earlier || overlaps_later;
+
+        furthest_end = furthest_end.max(Some(assignment.end));
+    }
+
+    (0..assignments.len()).filter(|&i| overlapping[i]).collect()
+}
+
+fn parse_assignment(input: &str) -> IResult<&str, Assignment<u8>> {
+    map(
+        verify(separated_pair(u8, char('-'), u8), |(start, end)| {
+            start <= end
+        }),
+        |(start, end)| Assignment { start, end },
+    )(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (Assignment<u8>, Assignment<u8>)> {
+    separated_pair(parse_assignment, char(','), parse_assignment)(input)
+}
+
+fn parse_pairs(input: &str) -> anyhow::Result<Vec<(Assignment<u8>, Assignment<u8>)>> {
+    let (_, pairs) =
+        all_consuming(many0(terminated(parse_pair, opt(alt((line_ending, eof))))))(input)
+            .map_err(|_| anyhow::anyhow!("Parse error!"))?;
+
+    Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day04_test.txt");
+
+    #[test]
+    fn parse_assignment_works() {
+        let input = "2-4";
+        let expected = Assignment { start: 2, end: 4 };
+
+        assert_eq!(expected, parse_assignment(input).unwrap().1);
+        assert!(parse_assignment("4-2").is_err());
+    }
+
+    #[test]
+    fn parse_pair_works() {
+        let input = "2-4,6-8";
+        let (a, b) = parse_pair(input).unwrap().1;
+
+        assert_eq!(Relation::Before, a.relation(b));
+    }
+
+    #[test]
+    fn part_1_works() {
+        assert_eq!(2, run(TEST_INPUT).unwrap().0);
+    }
+
+    #[test]
+    fn part_2_works() {
+        assert_eq!(4, run(TEST_INPUT).unwrap().1);
+    }
+
+    #[test]
+    fn classifies_all_thirteen_relations() {
+        let assignment = |start, end| Assignment::new(start, end).unwrap();
+        let other = assignment(10, 20);
+
+        for (start, end, expected) in [
+            (1, 5, Relation::Before),
+            (1, 9, Relation::Meets),
+            (5, 15, Relation::Overlaps),
+            (10, 15, Relation::Starts),
+            (12, 15, Relation::During),
+            (15, 20, Relation::Finishes),
+            (10, 20, Relation::Equals),
+            (5, 20, Relation::FinishedBy),
+            (5, 25, Relation::Contains),
+            (10, 25, Relation::StartedBy),
+            (15, 25, Relation::OverlappedBy),
+            (21, 25, Relation::MetBy),
+            (25, 30, Relation::After),
+        ] {
+            let a = assignment(start, end);
+            assert_eq!(expected, a.relation(other), "{:?}", a);
+            assert_eq!(expected.inverse(), other.relation(a), "{:?}", a);
+
+            // The relation doesn't depend on the type the sections are numbered with
+            let a = Assignment::<i64>::new(start as i64 - 50, end as i64 - 50).unwrap();
+            let other = Assignment::new(-40, -30).unwrap();
+            assert_eq!(expected, a.relation(other));
+        }
+
+        // Sharing a single section is an overlap rather than meeting
+        assert_eq!(
+            Relation::Overlaps,
+            assignment(1, 10).relation(assignment(10, 12))
+        );
+        // The last section a type can number doesn't overflow
+        assert_eq!(
+            Relation::MetBy,
+            assignment(u8::MAX, u8::MAX).relation(assignment(0, u8::MAX - 1))
+        );
+        assert_eq!(None, Assignment::new(2, 1));
+    }
+
+    #[test]
+    fn sweeps_the_example() {
+        let assignments = assignments(TEST_INPUT).unwrap();
+        let run = |start, end, count| (Assignment { start, end }, count);
+
+        assert_eq!(
+            vec![
+                run(2, 2, 4),
+                run(3, 3, 5),
+                run(4, 5, 7),
+                run(6, 6, 8),
+                run(7, 7, 6),
+                run(8, 8, 4),
+                run(9, 9, 1)
+            ],
+            coverage(&assignments)
+        );
+        assert_eq!(
+            (8, vec![Assignment { start: 6, end: 6 }]),
+            most_covered(&assignments)
+        );
+    }
+
+    /// The coverage of every section and the overlapping pairs, found by comparing everything.
+    fn brute_force(assignments: &[Assignment<u8>]) -> (Vec<usize>, Vec<(usize, usize)>) {
+        let mut counts = vec![0; 256];
+        for assignment in assignments {
+            for section in assignment.start..=assignment.end {
+                counts[section as usize] += 1;
+            }
+        }
+
+        let mut pairs = Vec::new();
+        for i in 0..assignments.len() {
+            for j in i + 1..assignments.len() {
+                if assignments[i].relation(assignments[j]).shares_sections() {
+                    pairs.push((i, j));
+                }
+            }
+        }
+
+        (counts, pairs)
+    }
+
+    #[test]
+    fn sweep_agrees_with_brute_force() {
+        let generator = crate::y2022::generate::find("day04").unwrap();
+        for seed in 0..10 {
+            let assignments = assignments(&generator.seeded(seed, 100)).unwrap();
+            let (counts, pairs) = brute_force(&assignments);
+
+            let mut swept = vec![0; 256];
+            for (run, count) in coverage(&assignments) {
+                for section in run.start..=run.end {
+                    swept[section as usize] = count;
+                }
+            }
+            assert_eq!(counts, swept);
+
+            let (most, sections) = most_covered(&assignments);
+            assert_eq!(counts.iter().max().copied().unwrap(), most);
+            for run in sections {
+                assert!((run.start..=run.end).all(|section| counts[section as usize] == most));
+            }
+
+            assert_eq!(pairs, overlapping_pairs(&assignments));
+
+            let elves: Vec<_> = pairs
+                .iter()
+                .flat_map(|&(i, j)| [i, j])
+                .sorted()
+                .dedup()
+                .collect();
+            assert_eq!(elves, overlapping_elves(&assignments));
+        }
+    }
+
+    #[test]
+    fn sweeps_to_the_last_section() {
+        let assignments = [
+            Assignment::new(250u8, 255).unwrap(),
+            Assignment::new(255, 255).unwrap(),
+            Assignment::new(0, 3).unwrap(),
+            Assignment::new(4, 4).unwrap(),
+        ];
+
+        assert_eq!(
+            vec![
+                (Assignment { start: 0, end: 4 }, 1),
+                (
+                    Assignment {
+                        start: 250,
+                        end: 254
+                    },
+                    1
+                ),
+                (
+                    Assignment {
+                        start: 255,
+                        end: 255
+                    },
+                    2
+                )
+            ],
+            coverage(&assignments)
+        );
+        assert_eq!(vec![(0, 1)], overlapping_pairs(&assignments));
+        assert_eq!(vec![0, 1], overlapping_elves(&assignments));
+    }
+}
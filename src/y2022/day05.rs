@@ -0,0 +1,765 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, u32};
+use nom::combinator::{all_consuming, map};
+use nom::error::ParseError;
+use nom::sequence::{delimited, preceded, tuple};
+use nom::IResult;
+
+use crate::registry::Params;
+use crate::utils::sections;
+use crate::viz::{Animation, Colour, Frame, Render};
+
+pub fn run(input: &str) -> anyhow::Result<(String, String)> {
+    run_with(input, &Params::default())
+}
+
+/// Solves with a crane for part 2 that lifts at most `capacity` crates at once, which defaults to 0
+/// for the CrateMover 9001 that lifts any number.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(String, String)> {
+    let crane: Box<dyn Crane> = match NonZeroUsize::new(params.get("capacity", 0)?) {
+        Some(capacity) => Box::new(LimitedCrane { capacity }),
+        None => Box::new(CrateMover9001),
+    };
+    let (supplies, moves) = parse_input(input)?;
+
+    Ok((
+        rearrange(supplies.clone(), &moves, &CrateMover9000, Mode::Strict)?,
+        rearrange(supplies, &moves, crane.as_ref(), Mode::Strict)?,
+    ))
+}
+
+/// Rearranges the stacks of `input` with the crane model `crane`, as named by [`crane`], returning
+/// the crates on top of the stacks.
+pub fn run_with_crane(input: &str, crane: &str, mode: Mode) -> anyhow::Result<String> {
+    let crane = self::crane(crane)?;
+    let (supplies, moves) = parse_input(input)?;
+
+    rearrange(supplies, &moves, crane.as_ref(), mode)
+}
+
+/// Animates the CrateMover 9001 rearranging the stacks, a move at a time.
+pub fn visualise(input: &str, _: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let (supplies, moves) = parse_input(input)?;
+    let mut history = History::record(supplies, moves, &CrateMover9001, Mode::Strict)?;
+    history.seek(0);
+
+    animation.play(&mut history, |history| {
+        Ok(history.step_forward() && !history.at_end())
+    })
+}
+
+fn rearrange(
+    mut supplies: Supplies,
+    moves: &[Move],
+    crane: &dyn Crane,
+    mode: Mode,
+) -> anyhow::Result<String> {
+    for step in moves {
+        supplies.apply(step, crane, mode)?;
+    }
+
+    Ok(supplies.tops())
+}
+
+/// How to treat moves that can't be made as they're written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Reports the first move from or to a stack that doesn't exist, or of more crates than the
+    /// stack has, as an error
+    Strict,
+    /// Skips moves from or to stacks that don't exist, and moves as many crates as there are
+    Lenient,
+}
+
+/// A model of crane, each of which moves crates between stacks in its own way.
+///
+/// Cranes are only given moves that [`Supplies`] has checked: `from` and `to` are indices of stacks
+/// counting from 0, and `amount` is at most the number of crates on stack `from`.
+pub trait Crane {
+    fn apply(&self, supplies: &mut Supplies, from: usize, to: usize, amount: usize);
+}
+
+/// Moves crates one at a time, reversing their order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn apply(&self, supplies: &mut Supplies, from: usize, to: usize, amount: usize) {
+        for _ in 0..amount {
+            supplies.lift(from, to, 1);
+        }
+    }
+}
+
+/// Moves crates all at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn apply(&self, supplies: &mut Supplies, from: usize, to: usize, amount: usize) {
+        supplies.lift(from, to, amount);
+    }
+}
+
+/// Moves at most `capacity` crates at once, keeping the order of each lift, so that a move of more
+/// takes several lifts from the top of the stack.
+pub struct LimitedCrane {
+    pub capacity: NonZeroUsize,
+}
+
+impl Crane for LimitedCrane {
+    fn apply(&self, supplies: &mut Supplies, from: usize, to: usize, amount: usize) {
+        let mut remaining = amount;
+        while remaining > 0 {
+            let lift = remaining.min(self.capacity.get());
+            supplies.lift(from, to, lift);
+            remaining -= lift;
+        }
+    }
+}
+
+/// The crane model called `name`: `9000`, `9001`, or `limited:N` for one that lifts at most `N`
+/// crates at once.
+pub fn crane(name: &str) -> anyhow::Result<Box<dyn Crane>> {
+    match name {
+        "9000" => Ok(Box::new(CrateMover9000)),
+        "9001" => Ok(Box::new(CrateMover9001)),
+        _ => match name.strip_prefix("limited:").map(str::parse) {
+            Some(Ok(capacity)) => Ok(Box::new(LimitedCrane { capacity })),
+            _ => bail!(
+                "expected a crane of 9000, 9001 or limited:N, where N > 0, got {:?}",
+                name
+            ),
+        },
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Supplies {
+    /// The crates of each stack, from the bottom up
+    stacks: Vec<Vec<u8>>,
+}
+
+impl Supplies {
+    /// Makes `step` with `crane`, checking that it can be made first.
+    fn apply(&mut self, step: &Move, crane: &dyn Crane, mode: Mode) -> anyhow::Result<()> {
+        let instr = &step.instr;
+        let stacks = 1..=self.stacks.len();
+        for stack in [instr.from, instr.to] {
+            if !stacks.contains(&stack) {
+                match mode {
+                    Mode::Strict => bail!(
+                        "line {}: {}: there is no stack {}, only 1 to {}",
+                        step.line,
+                        instr,
+                        stack,
+                        self.stacks.len()
+                    ),
+                    Mode::Lenient => return Ok(()),
+                }
+            }
+        }
+
+        let height = self.stacks[instr.from - 1].len();
+        if mode == Mode::Strict && instr.amount > height {
+            bail!(
+                "line {}: {}: stack {} only has {} crates",
+                step.line,
+                instr,
+                instr.from,
+                height
+            );
+        }
+
+        let (from, to) = (instr.from - 1, instr.to - 1);
+        crane.apply(self, from, to, instr.amount.min(height));
+        Ok(())
+    }
+
+    /// The crates of each stack, from the bottom up.
+    pub fn stacks(&self) -> &[Vec<u8>] {
+        &self.stacks
+    }
+
+    /// Moves the top `amount` crates of stack `from` onto stack `to` at once, keeping their order,
+    /// where stacks are counted from 0. Panics if either stack doesn't exist.
+    pub fn lift(&mut self, from: usize, to: usize, amount: usize) {
+        // Moving crates onto the stack they came from leaves it as it was
+        if from == to {
+            return;
+        }
+
+        // Split the stacks between the two so that both can be borrowed mutably
+        let (low, high) = self.stacks.split_at_mut(from.max(to));
+        let (from, to) = if from < to {
+            (&mut low[from], &mut high[0])
+        } else {
+            (&mut high[0], &mut low[to])
+        };
+
+        // Take at most the crates on the stack, in case a move asks for more than it holds
+        let offset = from.len() - amount.min(from.len());
+        to.extend_from_slice(&from[offset..]);
+        from.truncate(offset);
+    }
+
+    fn tops(&self) -> String {
+        let tops = self
+            .stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .copied()
+            .collect();
+
+        String::from_utf8(tops).unwrap()
+    }
+}
+
+/// Draws the stacks as the puzzle does, with a footer numbering them.
+impl fmt::Display for Supplies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+        for row in (0..height).rev() {
+            let cells: Vec<_> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(&elf_crate) => format!("[{}]", elf_crate as char),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+
+        let footer: Vec<_> = (1..=self.stacks.len())
+            .map(|stack| format!("{:^3}", stack))
+            .collect();
+        write!(f, "{}", footer.join(" "))
+    }
+}
+
+impl FromStr for Supplies {
+    type Err = anyhow::Error;
+
+    /// Parses a drawing of the stacks, whose last line numbers them. The crates of each stack are
+    /// in a column four characters along from the last, as they're drawn `[A] `.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().rev();
+        let footer = lines
+            .next()
+            .ok_or_else(|| anyhow!("the drawing is empty"))?;
+
+        let mut count = 0;
+        for label in footer.split_whitespace() {
+            count += 1;
+            if label.parse() != Ok(count) {
+                bail!("expected stack {} in the footer, got {:?}", count, label);
+            }
+        }
+
+        let mut stacks = vec![Vec::new(); count];
+        // Rows may have lost their trailing whitespace, or have more of it, so crates are picked
+        // out by column rather than assuming every row is `4 * count` bytes long
+        for line in lines {
+            for (i, &elf_crate) in line.as_bytes().iter().skip(1).step_by(4).enumerate() {
+                if !elf_crate.is_ascii_uppercase() {
+                    continue;
+                }
+                stacks
+                    .get_mut(i)
+                    .ok_or_else(|| {
+                        anyhow!("crate {:?} is beyond the last stack", elf_crate as char)
+                    })?
+                    .push(elf_crate);
+            }
+        }
+
+        Ok(Self { stacks })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CraneInstr {
+    pub amount: usize,
+    /// The stack to move crates from, counting from 1 as the puzzle does
+    pub from: usize,
+    /// The stack to move crates to, counting from 1 as the puzzle does
+    pub to: usize,
+}
+
+impl fmt::Display for CraneInstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.from, self.to)
+    }
+}
+
+/// A move of the procedure, along with the line it's on.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Move {
+    pub line: usize,
+    pub instr: CraneInstr,
+}
+
+/// Every state of the stacks through a procedure, so that it can be stepped through in either
+/// direction.
+pub struct History {
+    /// The stacks before any moves, and after each of them
+    states: Vec<Supplies>,
+    moves: Vec<Move>,
+    /// The number of moves made in the current state
+    position: usize,
+}
+
+impl History {
+    /// Makes every move with `crane`, keeping every state, and leaves the history at the end.
+    pub fn record(
+        supplies: Supplies,
+        moves: Vec<Move>,
+        crane: &dyn Crane,
+        mode: Mode,
+    ) -> anyhow::Result<Self> {
+        let mut states = Vec::with_capacity(moves.len() + 1);
+        states.push(supplies);
+        for step in &moves {
+            let mut next = states.last().unwrap().clone();
+            next.apply(step, crane, mode)?;
+            states.push(next);
+        }
+
+        Ok(Self {
+            position: moves.len(),
+            states,
+            moves,
+        })
+    }
+
+    /// The number of moves.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.position == self.moves.len()
+    }
+
+    /// The stacks after the first `position` moves.
+    pub fn current(&self) -> &Supplies {
+        &self.states[self.position]
+    }
+
+    /// The move that led to the current state, if any have been made.
+    pub fn last_move(&self) -> Option<&Move> {
+        self.position.checked_sub(1).map(|i| &self.moves[i])
+    }
+
+    /// Makes the next move, returning whether there was one.
+    pub fn step_forward(&mut self) -> bool {
+        let moved = !self.at_end();
+        self.position += usize::from(moved);
+        moved
+    }
+
+    /// Undoes the last move, returning whether there was one.
+    pub fn step_back(&mut self) -> bool {
+        let moved = self.position > 0;
+        self.position -= usize::from(moved);
+        moved
+    }
+
+    /// Goes to the state after the first `position` moves, or the last state if there are fewer.
+    pub fn seek(&mut self, position: usize) {
+        self.position = position.min(self.moves.len());
+    }
+}
+
+impl Render for History {
+    fn render(&self) -> Frame {
+        let drawing = self.current().to_string();
+        let lines: Vec<_> = drawing.lines().collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        let mut frame = Frame::new((0, 0), width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let colour = if y + 1 == lines.len() {
+                Colour::GREY
+            } else {
+                Colour::YELLOW
+            };
+            for (x, glyph) in line.chars().enumerate() {
+                frame.set((x as i64, y as i64), glyph, Some(colour));
+            }
+        }
+
+        frame.set_caption(match self.last_move() {
+            Some(step) => format!(
+                "{} of {}: {} (line {})",
+                self.position,
+                self.len(),
+                step.instr,
+                step.line
+            ),
+            None => format!("0 of {}", self.len()),
+        });
+
+        frame
+    }
+}
+
+fn parse_input(input: &str) -> anyhow::Result<(Supplies, Vec<Move>)> {
+    let [stacks, moves] = sections(input)[..] else {
+        return Err(anyhow!("parse error"));
+    };
+
+    // `sections` returns slices of the input, so the lines before the moves can be counted
+    let offset = moves.as_ptr() as usize - input.as_ptr() as usize;
+    let first_line = input[..offset].lines().count() + 1;
+
+    let stacks: Supplies = stacks.parse()?;
+    let moves = parse_moves(moves, first_line)?;
+
+    Ok((stacks, moves))
+}
+
+// A combinator that takes a parser `inner` and produces a parser that also consumes both leading and
+// trailing whitespace, returning the output of `inner`.
+fn ws<'a, F, O, E: ParseError<&'a str>>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
+where
+    F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
+{
+    delimited(multispace0, inner, multispace0)
+}
+
+// move 7 from 3 to 9
+fn parse_crane_move(input: &str) -> IResult<&str, CraneInstr> {
+    map(
+        tuple((
+            preceded(ws(tag("move")), u32),
+            preceded(ws(tag("from")), u32),
+            preceded(ws(tag("to")), u32),
+        )),
+        |(a, b, c)| CraneInstr {
+            amount: a as usize,
+            from: b as usize,
+            to: c as usize,
+        },
+    )(input)
+}
+
+/// Parses the moves, the first of which is on line `first_line` of the input.
+fn parse_moves(input: &str, first_line: usize) -> anyhow::Result<Vec<Move>> {
+    let mut moves = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (_, instr) = all_consuming(parse_crane_move)(line)
+            .map_err(|_| anyhow!("line {}: invalid move {:?}", first_line + i, line))?;
+        moves.push(Move {
+            line: first_line + i,
+            instr,
+        });
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day05_test.txt");
+
+    #[test]
+    fn parse_crane_move_works() {
+        let input = "move 7 from 3 to 9";
+        let expected = CraneInstr {
+            amount: 7,
+            from: 3,
+            to: 9,
+        };
+
+        assert_eq!(expected, parse_crane_move(input).unwrap().1);
+    }
+
+    #[test]
+    fn parse_moves_works() {
+        let input = "\
+move 7 from 3 to 9
+move 8 from 1 to 7";
+        let expected = vec![
+            Move {
+                line: 6,
+                instr: CraneInstr {
+                    amount: 7,
+                    from: 3,
+                    to: 9,
+                },
+            },
+            Move {
+                line: 7,
+                instr: CraneInstr {
+                    amount: 8,
+                    from: 1,
+                    to: 7,
+                },
+            },
+        ];
+
+        assert_eq!(expected, parse_moves(input, 6).unwrap());
+        assert_eq!(
+            "line 7: invalid move \"move 8 from 1\"",
+            parse_moves("move 7 from 3 to 9\nmove 8 from 1", 6)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_1_works() {
+        let (stacks, moves) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            "CMZ",
+            rearrange(stacks, &moves, &CrateMover9000, Mode::Strict).unwrap()
+        );
+    }
+
+    #[test]
+    fn part_2_works() {
+        let (stacks, moves) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            "MCD",
+            rearrange(stacks, &moves, &CrateMover9001, Mode::Strict).unwrap()
+        );
+    }
+
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_INPUT) {
+            assert_eq!(("CMZ".into(), "MCD".into()), run(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn selects_cranes_by_name() {
+        assert_eq!(
+            "CMZ",
+            run_with_crane(TEST_INPUT, "9000", Mode::Strict).unwrap()
+        );
+        assert_eq!(
+            "MCD",
+            run_with_crane(TEST_INPUT, "9001", Mode::Strict).unwrap()
+        );
+        // The second move lifts two crates and then the last one
+        assert_eq!(
+            "MCZ",
+            run_with_crane(TEST_INPUT, "limited:2", Mode::Strict).unwrap()
+        );
+        for name in ["9002", "limited:0", "limited:", "limited:x"] {
+            assert!(crane(name).is_err(), "{}", name);
+        }
+
+        let params = Params::from([("capacity", 2)]);
+        assert_eq!(
+            ("CMZ".into(), "MCZ".into()),
+            run_with(TEST_INPUT, &params).unwrap()
+        );
+        let params = Params::from([("capacity", 0)]);
+        assert_eq!(
+            ("CMZ".into(), "MCD".into()),
+            run_with(TEST_INPUT, &params).unwrap()
+        );
+        assert!(run_with(TEST_INPUT, &Params::from([("capacity", -1)])).is_err());
+    }
+
+    /// A crane that records the moves it's given, only using what's public to make them.
+    struct Recorder(std::cell::RefCell<Vec<(usize, usize, usize)>>);
+
+    impl Crane for Recorder {
+        fn apply(&self, supplies: &mut Supplies, from: usize, to: usize, amount: usize) {
+            assert!(amount <= supplies.stacks()[from].len());
+            self.0.borrow_mut().push((from, to, amount));
+            supplies.lift(from, to, amount);
+        }
+    }
+
+    #[test]
+    fn cranes_are_given_checked_moves_from_0() {
+        let (supplies, moves) = parse_input(TEST_INPUT).unwrap();
+        let recorder = Recorder(Default::default());
+        rearrange(supplies.clone(), &moves, &recorder, Mode::Strict).unwrap();
+
+        assert_eq!(
+            vec![(1, 0, 1), (0, 2, 3), (1, 0, 2), (0, 1, 1)],
+            recorder.0.take()
+        );
+
+        // Leniently, a move of more crates than there are is cut short before the crane sees it
+        let recorder = Recorder(Default::default());
+        let long_move = Move {
+            line: 6,
+            instr: CraneInstr {
+                amount: 5,
+                from: 2,
+                to: 1,
+            },
+        };
+        rearrange(supplies, &[long_move], &recorder, Mode::Lenient).unwrap();
+
+        assert_eq!(vec![(1, 0, 3)], recorder.0.take());
+    }
+
+    #[test]
+    fn limited_cranes_are_between_the_others() {
+        let generator = crate::y2022::generate::find("day05").unwrap();
+
+        for seed in 0..10 {
+            let input = generator.seeded(seed, 50);
+            let (supplies, moves) = parse_input(&input).unwrap();
+            let biggest = moves.iter().map(|step| step.instr.amount).max().unwrap();
+            let biggest = NonZeroUsize::new(biggest).unwrap();
+
+            assert_eq!(
+                rearrange(supplies.clone(), &moves, &CrateMover9000, Mode::Strict).unwrap(),
+                rearrange(
+                    supplies.clone(),
+                    &moves,
+                    &LimitedCrane {
+                        capacity: NonZeroUsize::MIN
+                    },
+                    Mode::Strict
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                rearrange(supplies.clone(), &moves, &CrateMover9001, Mode::Strict).unwrap(),
+                rearrange(
+                    supplies,
+                    &moves,
+                    &LimitedCrane { capacity: biggest },
+                    Mode::Strict
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn counts_stacks_from_the_footer() {
+        // Eleven stacks, with ragged trailing whitespace
+        let drawing = [
+            "                                        [K] \t",
+            "[A]                                     [L]",
+            "[B] [C] [D] [E] [F] [G] [H] [I] [J] [X] [M]   \t",
+            " 1   2   3   4   5   6   7   8   9  10  11  ",
+        ]
+        .join("\n");
+        let supplies: Supplies = drawing.parse().unwrap();
+
+        assert_eq!(11, supplies.stacks.len());
+        assert_eq!(b"BA", supplies.stacks[0].as_slice());
+        assert_eq!(b"X", supplies.stacks[9].as_slice());
+        assert_eq!(b"MLK", supplies.stacks[10].as_slice());
+        assert_eq!("ACDEFGHIJXK", supplies.tops());
+
+        let input = format!("{}\n\nmove 2 from 11 to 10\n", drawing);
+        assert_eq!(
+            "ACDEFGHIJKM",
+            run_with_crane(&input, "9001", Mode::Strict).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_drawings() {
+        assert!("[A] [B]\n 1 ".parse::<Supplies>().is_err());
+        assert!("[A]\n 1   3 ".parse::<Supplies>().is_err());
+        assert!("[A]\n".parse::<Supplies>().is_err());
+    }
+
+    #[test]
+    fn parses_generated_drawings() {
+        let generator = crate::y2022::generate::find("day05").unwrap();
+
+        for seed in 0..20 {
+            let input = generator.seeded(seed, 50);
+            let (supplies, moves) = parse_input(&input).unwrap();
+            let (drawing, _) = input.split_once("\n\n").unwrap();
+
+            let crates: usize = supplies.stacks.iter().map(Vec::len).sum();
+
+            assert_eq!(drawing.matches('[').count(), crates);
+            assert_eq!(50, moves.len());
+        }
+    }
+
+    #[test]
+    fn reports_invalid_moves_by_line() {
+        let input = TEST_INPUT.replace("move 3 from 1 to 3", "move 3 from 0 to 3");
+        assert_eq!(
+            "line 7: move 3 from 0 to 3: there is no stack 0, only 1 to 3",
+            run(&input).unwrap_err().to_string()
+        );
+
+        let input = TEST_INPUT.replace("move 1 from 1 to 2", "move 4 from 1 to 2");
+        assert_eq!(
+            "line 9: move 4 from 1 to 2: stack 1 only has 2 crates",
+            run(&input).unwrap_err().to_string()
+        );
+
+        // Leniently, moves to missing stacks are skipped and overlong moves are cut short
+        let input = TEST_INPUT.replace("move 3 from 1 to 3", "move 3 from 0 to 3");
+        assert_eq!(
+            "CMP",
+            run_with_crane(&input, "9000", Mode::Lenient).unwrap()
+        );
+        let input = TEST_INPUT.replace("move 1 from 1 to 2", "move 4 from 1 to 2");
+        assert!(run_with_crane(&input, "9001", Mode::Lenient).is_ok());
+    }
+
+    #[test]
+    fn steps_through_the_history() {
+        let (supplies, moves) = parse_input(TEST_INPUT).unwrap();
+        let mut history =
+            History::record(supplies.clone(), moves, &CrateMover9000, Mode::Strict).unwrap();
+
+        assert_eq!(4, history.len());
+        assert_eq!("CMZ", history.current().tops());
+
+        history.seek(0);
+        assert_eq!(&supplies, history.current());
+        assert!(!history.step_back());
+        assert!(history.step_forward());
+        assert_eq!("DCP", history.current().tops());
+        assert_eq!(Some(6), history.last_move().map(|step| step.line));
+
+        while history.step_forward() {}
+        assert_eq!(4, history.position());
+        assert!(history.step_back());
+        // Undoing the last move leaves stack 2 empty again
+        assert_eq!("MZ", history.current().tops());
+    }
+
+    #[test]
+    fn draws_supplies_as_the_puzzle_does() {
+        let (supplies, _) = parse_input(TEST_INPUT).unwrap();
+        let (drawing, _) = TEST_INPUT.split_once("\n\n").unwrap();
+
+        assert_eq!(drawing, supplies.to_string());
+        assert_eq!(supplies, supplies.to_string().parse().unwrap());
+    }
+}
@@ -0,0 +1,237 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+
+pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
+    Ok((
+        unique_run_big_o_n::<4>(input).unwrap(),
+        unique_run_big_o_n::<14>(input).unwrap(),
+    ))
+}
+
+/// Streaming variant of `run` that looks for both markers in a single pass over the datastream,
+/// stopping as soon as the start-of-message marker is found
+pub fn run_stream<R: BufRead>(mut reader: R) -> anyhow::Result<(usize, usize)> {
+    let mut packet = Window::<4>::new();
+    let mut message = Window::<14>::new();
+    let mut part_1 = None;
+    let mut position = 0;
+
+    // The datastream ends at the first whitespace, wherever it falls within the reader's buffer
+    'read: loop {
+        let buf = reader.fill_buf()?;
+        let len = buf.len();
+        if len == 0 {
+            break;
+        }
+
+        for &b in buf {
+            if b.is_ascii_whitespace() {
+                break 'read;
+            }
+            anyhow::ensure!(b.is_ascii_lowercase(), "unexpected byte {:?}", b as char);
+
+            position += 1;
+            if packet.push(b) && part_1.is_none() {
+                part_1 = Some(position);
+            }
+            // A unique run of 14 contains a unique run of 4, so part 1 has been found by now
+            if message.push(b) {
+                return Ok((part_1.unwrap(), position));
+            }
+        }
+
+        reader.consume(len);
+    }
+
+    let part_1 = part_1.context("no start-of-packet marker")?;
+    anyhow::bail!("no start-of-message marker after {}", part_1)
+}
+
+#[allow(dead_code)]
+/// K is the length of the unique run
+fn unique_run_big_o_nk<const K: usize>(input: &str) -> Option<usize> {
+    input
+        .as_bytes()
+        .windows(K)
+        .enumerate()
+        .find(|(_, w)| {
+            w.iter()
+                .fold(0u32, |mask, b| mask | (1 << (b - b'a')))
+                .count_ones()
+                == K as u32
+        })
+        .map(|(i, _)| i + K)
+}
+
+#[inline]
+const fn normalize_letter(ascii_code: u8) -> usize {
+    (ascii_code - b'a') as usize
+}
+
+struct UniqueLetters {
+    counts: [u8; 26],
+    uniq: usize,
+}
+
+impl UniqueLetters {
+    fn new() -> Self {
+        Self {
+            counts: [0; 26],
+            uniq: 0,
+        }
+    }
+
+    /// Returns uniq after inserting a letter
+    #[inline]
+    fn insert(&mut self, ascii_code: u8) -> usize {
+        let entry = &mut self.counts[normalize_letter(ascii_code)];
+        *entry += 1;
+        if *entry == 1 {
+            self.uniq += 1;
+        }
+
+        self.uniq
+    }
+
+    /// Returns uniq after removing a letter
+    #[inline]
+    fn remove(&mut self, ascii_code: u8) -> usize {
+        let entry = &mut self.counts[normalize_letter(ascii_code)];
+        *entry -= 1;
+        if *entry == 0 {
+            self.uniq -= 1;
+        }
+
+        self.uniq
+    }
+}
+
+/// The last `K` letters of a datastream
+struct Window<const K: usize> {
+    letters: UniqueLetters,
+    ring: [u8; K],
+    len: usize,
+}
+
+impl<const K: usize> Window<K> {
+    fn new() -> Self {
+        Self {
+            letters: UniqueLetters::new(),
+            ring: [0; K],
+            len: 0,
+        }
+    }
+
+    /// Pushes a letter, evicting the letter pushed `K` letters ago, and returns whether the last `K`
+    /// letters are unique
+    #[inline]
+    fn push(&mut self, ascii_code: u8) -> bool {
+        let slot = self.len % K;
+        if self.len >= K {
+            self.letters.remove(self.ring[slot]);
+        }
+        self.ring[slot] = ascii_code;
+        self.len += 1;
+
+        self.letters.insert(ascii_code) == K
+    }
+}
+
+/// K is the length of the unique run
+fn unique_run_big_o_n<const K: usize>(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+
+    let mut uniq = UniqueLetters::new();
+
+    for &b in bytes.iter().take(K) {
+        uniq.insert(b);
+    }
+
+    if uniq.uniq == K {
+        return Some(K);
+    }
+
+    for i in K..bytes.len() {
+        let to_remove = bytes[i - K];
+        let to_insert = bytes[i];
+
+        uniq.remove(to_remove);
+
+        if uniq.insert(to_insert) == K {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_works() {
+        let inputs = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(expected, unique_run_big_o_n::<4>(input).unwrap());
+        }
+    }
+
+    #[test]
+    fn part_2_works() {
+        let inputs = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(expected, unique_run_big_o_n::<14>(input).unwrap());
+        }
+    }
+
+    #[test]
+    fn unique_run_implementations_agree() {
+        let generator = crate::y2022::generate::find("day06").unwrap();
+
+        for seed in 0..20 {
+            let input = generator.seeded(seed, 500);
+
+            assert_eq!(
+                unique_run_big_o_nk::<4>(&input),
+                unique_run_big_o_n::<4>(&input)
+            );
+            assert_eq!(
+                unique_run_big_o_nk::<14>(&input),
+                unique_run_big_o_n::<14>(&input)
+            );
+        }
+    }
+
+    #[test]
+    fn stream_stops_at_whitespace() {
+        // Neither marker can be found before the newline, though both could be after it
+        let input = "aaaa\nabcdefghijklmnopqrstuvwxyz";
+        for capacity in 1..=input.len() {
+            let reader = std::io::BufReader::with_capacity(capacity, input.as_bytes());
+
+            assert!(run_stream(reader).is_err(), "{}", capacity);
+        }
+
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nmore";
+        for capacity in 1..=input.len() {
+            let reader = std::io::BufReader::with_capacity(capacity, input.as_bytes());
+
+            assert_eq!((7, 19), run_stream(reader).unwrap(), "{}", capacity);
+        }
+    }
+}
@@ -75,7 +75,7 @@ impl DirectoryHeap {
             let line = parse_line(line)?;
 
             match line {
-                Cd(d) if d == ".." => {
+                Cd("..") => {
                     cwd = heap.get_parent(cwd);
                 }
                 Cd(_) => {
@@ -158,11 +158,9 @@ fn parse_file(input: &str) -> IResult<&str, Line<'_>> {
 
 // A combinator that takes a parser `inner` and produces a parser that also consumes both leading and
 // trailing whitespace, returning the output of `inner`.
-fn ws<'a, F: 'a, O, E: ParseError<&'a str>>(
-    inner: F,
-) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
+fn ws<'a, F, O, E: ParseError<&'a str>>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
 where
-    F: Fn(&'a str) -> IResult<&'a str, O, E>,
+    F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
 {
     delimited(multispace0, inner, multispace0)
 }
@@ -171,30 +169,7 @@ where
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = "\
-$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k";
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day07_test.txt");
 
     #[test]
     fn parse_cd_works() {
@@ -241,4 +216,25 @@ $ ls
 
         assert_eq!(24933642, heap.part_2());
     }
+
+    #[test]
+    fn root_contains_every_file() {
+        let generator = crate::y2022::generate::find("day07").unwrap();
+
+        for seed in 0..20 {
+            let input = generator.seeded(seed, 30);
+            let heap = DirectoryHeap::build_heap_from_input(&input).unwrap();
+
+            let total: u64 = input
+                .lines()
+                .filter_map(|line| parse_file(line).ok())
+                .map(|(_, line)| match line {
+                    Line::File((size, _)) => size,
+                    _ => 0,
+                })
+                .sum();
+
+            assert_eq!(total, heap.heap[0].size);
+        }
+    }
 }
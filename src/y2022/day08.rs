@@ -1,9 +1,28 @@
+use crate::image::{Image, Palette};
+use crate::registry::Params;
+
 pub fn run(input: &str) -> anyhow::Result<(usize, u32)> {
     let forest = Forest::<99, 99>::from_input(input);
 
     Ok((forest.visible(), forest.best_score()))
 }
 
+/// Draws the scenic score of every tree, on a log scale as a few trees score far higher than the
+/// rest.
+pub fn draw(input: &str, _: &Params, palette: &dyn Palette) -> anyhow::Result<Image> {
+    let forest = Forest::<99, 99>::from_input(input);
+    let width = input.lines().next().map_or(0, str::len).min(99);
+    let height = input.lines().count().min(99);
+
+    let scores: Vec<_> = forest.forest[..height]
+        .iter()
+        .flat_map(|row| &row[..width])
+        .map(|tree| Some((tree.score as f64).ln_1p()))
+        .collect();
+
+    Ok(Image::from_values(width, height, &scores, palette))
+}
+
 struct Forest<const X: usize, const Y: usize> {
     forest: [[Tree; X]; Y],
 }
@@ -30,8 +49,8 @@ impl<const X: usize, const Y: usize> Forest<X, Y> {
     fn from_input(input: &str) -> Self {
         let mut forest: [[Tree; X]; Y] = [[Default::default(); X]; Y];
 
-        for (y, row) in input.as_bytes().chunks(X + 1).enumerate() {
-            for (x, height) in row.iter().map(|b| b - b'0').enumerate().take(X) {
+        for (y, row) in input.lines().enumerate().take(Y) {
+            for (x, height) in row.bytes().map(|b| b - b'0').enumerate().take(X) {
                 forest[y][x].height = height;
             }
         }
@@ -140,12 +159,7 @@ impl TreeLine {
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = "\
-30373
-25512
-65332
-33549
-35390";
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day08_test.txt");
 
     #[test]
     fn part_1_works() {
@@ -158,4 +172,13 @@ mod tests {
         let forest = Forest::<5, 5>::from_input(TEST_INPUT);
         assert_eq!(8, forest.best_score());
     }
+
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_INPUT) {
+            let forest = Forest::<5, 5>::from_input(&input);
+            assert_eq!(21, forest.visible());
+            assert_eq!(8, forest.best_score());
+        }
+    }
 }
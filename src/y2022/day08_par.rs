@@ -1,5 +1,12 @@
+use crate::registry::Params;
+
 pub fn run(input: &str) -> anyhow::Result<(usize, u32)> {
-    let forest = Forest::from_input(input, 99, 99);
+    run_with(input, &Params::default())
+}
+
+/// Solves for a forest of `rows` by `cols` trees, which default to the size of the real forest.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(usize, u32)> {
+    let forest = Forest::from_input(input, params.get("rows", 99)?, params.get("cols", 99)?);
 
     let stats = classify_forest(&forest);
 
@@ -19,8 +26,8 @@ impl Forest {
     fn from_input(input: &str, rows: usize, cols: usize) -> Self {
         let mut trees = vec![0; rows * cols];
 
-        for (y, row) in input.as_bytes().chunks(cols + 1).enumerate() {
-            for (x, height) in row.iter().map(|b| b - b'0').enumerate().take(cols) {
+        for (y, row) in input.lines().enumerate().take(rows) {
+            for (x, height) in row.bytes().map(|b| b - b'0').enumerate().take(cols) {
                 trees[y * cols + x] = height;
             }
         }
@@ -60,9 +67,9 @@ fn classify_forest(forest: &Forest) -> Vec<Stat> {
 
     col_down
         .into_iter()
-        .zip(col_up.into_iter())
-        .zip(row_left.into_iter())
-        .zip(row_right.into_iter())
+        .zip(col_up)
+        .zip(row_left)
+        .zip(row_right)
         .map(|(((a, b), c), d)| a.combine(b).combine(c).combine(d))
         .collect()
 }
@@ -183,12 +190,7 @@ impl TreeLine {
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = "\
-30373
-25512
-65332
-33549
-35390";
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day08_test.txt");
 
     #[test]
     fn it_works() {
@@ -200,6 +202,30 @@ mod tests {
         assert_eq!(8, max);
     }
 
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_INPUT) {
+            let forest = Forest::from_input(&input, 5, 5);
+            let stats = classify_forest(&forest);
+            assert_eq!(21, stats.iter().filter(|stat| stat.visible).count());
+            assert_eq!(8, stats.iter().map(|stat| stat.score).max().unwrap());
+        }
+    }
+
+    #[test]
+    fn agrees_with_sequential_solution() {
+        let generator = crate::y2022::generate::find("day08_par").unwrap();
+
+        for seed in 0..5 {
+            let input = generator.seeded(seed, 99);
+
+            assert_eq!(
+                crate::y2022::day08::run(&input).unwrap(),
+                run(&input).unwrap()
+            );
+        }
+    }
+
     // Alt solution
     // fn combine_stats(
     //     rows: usize,
@@ -2,6 +2,9 @@ use std::ops;
 
 use hashbrown::HashSet;
 
+use crate::registry::Params;
+use crate::viz::{Animation, Colour, Frame, Render};
+
 pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     let moves = parse_moves(input)?;
     let mut rope = Rope::new(2);
@@ -15,6 +18,21 @@ pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     Ok((part_1, part_2))
 }
 
+/// Animates the ten knot rope of part 2, a step at a time.
+pub fn visualise(input: &str, _: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let moves = parse_moves(input)?;
+    let mut directions = moves
+        .iter()
+        .flat_map(|mov| std::iter::repeat_n(mov.direction, mov.distance as usize));
+
+    animation.play(&mut Rope::new(10), |rope| {
+        Ok(directions
+            .next()
+            .map(|direction| rope.step(direction))
+            .is_some())
+    })
+}
+
 #[derive(Debug)]
 struct Rope {
     knots: Vec<RopeVector>,
@@ -31,13 +49,17 @@ impl Rope {
     }
     fn mov(&mut self, mov: &Move) {
         for _ in 0..mov.distance {
-            self.knots[0] += mov.direction;
-            for i in 1..self.knots.len() {
-                let leader = self.knots[i - 1];
-                self.knots[i].follow(leader);
-            }
-            self.tail_history.insert(*self.knots.last().unwrap());
+            self.step(mov.direction);
+        }
+    }
+
+    fn step(&mut self, direction: RopeVector) {
+        self.knots[0] += direction;
+        for i in 1..self.knots.len() {
+            let leader = self.knots[i - 1];
+            self.knots[i].follow(leader);
         }
+        self.tail_history.insert(*self.knots.last().unwrap());
     }
 
     fn follow_moves(&mut self, moves: &[Move]) {
@@ -47,6 +69,38 @@ impl Rope {
     }
 }
 
+impl Render for Rope {
+    fn render(&self) -> Frame {
+        // Up is positive y for the rope, but downwards for frames
+        let point = |knot: &RopeVector| (knot.x as i64, -knot.y as i64);
+        let points = || self.knots.iter().chain(self.tail_history.iter()).map(point);
+        let min = points().fold((0, 0), |min, p| (min.0.min(p.0), min.1.min(p.1)));
+        let max = points().fold((0, 0), |max, p| (max.0.max(p.0), max.1.max(p.1)));
+
+        let mut frame = Frame::covering(min, max);
+        for visited in &self.tail_history {
+            frame.set(point(visited), '#', Some(Colour::GREY));
+        }
+        frame.set((0, 0), 's', Some(Colour::WHITE));
+        // Drawn from the tail up, so that knots nearer the head are drawn on top
+        for (i, knot) in self.knots.iter().enumerate().rev() {
+            let (glyph, colour) = match i {
+                0 => ('H', Colour::RED),
+                i => (char::from_digit(i as u32, 36).unwrap(), Colour::YELLOW),
+            };
+            frame.set(point(knot), glyph, Some(colour));
+        }
+
+        frame.set_focus(point(&self.knots[0]));
+        frame.set_caption(format!(
+            "{} positions visited by the tail",
+            self.tail_history.len()
+        ));
+
+        frame
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Move {
     direction: RopeVector,
@@ -118,15 +172,7 @@ fn parse_moves(input: &str) -> anyhow::Result<Vec<Move>> {
 mod tests {
     use super::*;
 
-    static TEST_MOVES: &str = "\
-R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
+    static TEST_MOVES: &str = include_str!("../../inputs/2022/day09_test.txt");
 
     #[test]
     fn part_1_works() {
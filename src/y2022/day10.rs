@@ -2,10 +2,9 @@ use std::io::{self, Write};
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{i64, newline};
-use nom::combinator::map;
-use nom::multi::many0;
-use nom::sequence::{preceded, terminated};
+use nom::character::complete::i64;
+use nom::combinator::{all_consuming, map};
+use nom::sequence::preceded;
 use nom::IResult;
 
 /// For use with benchmarks macro
@@ -16,16 +15,27 @@ pub fn run(input: &str) -> anyhow::Result<()> {
 
 /// For use with bin
 pub fn run_with<W: Write>(input: &str, out: &mut W) -> anyhow::Result<()> {
+    let (sum, crt) = solve(input)?;
+    writeln!(out, "Part 1: {}\n", sum)?;
+
+    out.write_all(crt.as_bytes())?;
+    out.flush()?;
+
+    Ok(())
+}
+
+/// For use with the registry, returns the signal sum and the image drawn on the CRT
+pub fn solve(input: &str) -> anyhow::Result<(i64, String)> {
     let instrs = parse_instrs(input)?;
     let mut cpu = Cpu::new();
     let register_vals = cpu.execute(&instrs);
 
     let sum = signal_sum(&register_vals);
-    writeln!(out, "Part 1: {}\n", sum)?;
 
-    draw(&register_vals, out)?;
+    let mut crt = Vec::new();
+    draw(&register_vals, &mut crt)?;
 
-    Ok(())
+    Ok((sum, String::from_utf8(crt)?))
 }
 
 fn signal_sum(register_vals: &[i64]) -> i64 {
@@ -50,7 +60,7 @@ fn draw<W: Write>(register_vals: &[i64], out: &mut W) -> anyhow::Result<()> {
 
     for line in pixels.chunks(40) {
         out.write_all(line)?;
-        out.write_all(&[b'\n'])?;
+        out.write_all(b"\n")?;
     }
     out.flush()?;
 
@@ -90,9 +100,16 @@ enum Instr {
 }
 
 fn parse_instrs(input: &str) -> anyhow::Result<Vec<Instr>> {
-    let (_, instrs) = many0(terminated(parse_instr, newline))(input)
-        .map_err(|_| anyhow::anyhow!("Parse error"))?;
-    Ok(instrs)
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            all_consuming(parse_instr)(line.trim())
+                .map(|(_, instr)| instr)
+                .map_err(|_| anyhow::anyhow!("Parse error on line {}", i + 1))
+        })
+        .collect()
 }
 
 fn parse_instr(input: &str) -> IResult<&str, Instr> {
@@ -105,7 +122,7 @@ fn parse_instr(input: &str) -> IResult<&str, Instr> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    static TEST_INSTRS: &str = include_str!("../inputs/day10_test.txt");
+    static TEST_INSTRS: &str = include_str!("../../inputs/2022/day10_test.txt");
 
     #[test]
     fn part_1_works() {
@@ -116,4 +133,13 @@ mod tests {
         let sum = signal_sum(&register_vals);
         assert_eq!(13140, sum);
     }
+
+    #[test]
+    fn handles_input_variants() {
+        let (sum, crt) = solve(TEST_INSTRS).unwrap();
+
+        for input in crate::input::variants(TEST_INSTRS) {
+            assert_eq!((sum, crt.clone()), solve(&input).unwrap());
+        }
+    }
 }
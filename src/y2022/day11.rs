@@ -2,10 +2,10 @@ use std::collections::VecDeque;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, u64};
-use nom::combinator::map;
-use nom::multi::separated_list0;
-use nom::sequence::{delimited, pair, preceded, terminated};
+use nom::character::complete::u64;
+use nom::combinator::{all_consuming, map};
+use nom::multi::{many0, separated_list0};
+use nom::sequence::{delimited, pair, preceded};
 use nom::IResult;
 
 use crate::utils::ws;
@@ -94,7 +94,7 @@ struct Test {
 impl Test {
     /// Where to throw the item
     fn evaluate(&self, item: usize) -> usize {
-        if item % self.div == 0 {
+        if item.is_multiple_of(self.div) {
             self.conds[0]
         } else {
             self.conds[1]
@@ -103,7 +103,7 @@ impl Test {
 }
 
 fn parse_monkeys(input: &str) -> anyhow::Result<Vec<Monkey>> {
-    let (_, monkeys) = separated_list0(line_ending, parse_monkey)(input)
+    let (_, monkeys) = all_consuming(many0(ws(parse_monkey)))(input)
         .map_err(|_| anyhow::anyhow!("Parse error"))?;
 
     Ok(monkeys)
@@ -118,14 +118,8 @@ fn parse_monkeys(input: &str) -> anyhow::Result<Vec<Monkey>> {
 fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
     map(
         preceded(
-            delimited(tag("Monkey "), u64, tag(":\n")),
-            pair(
-                terminated(parse_starting_items, line_ending),
-                pair(
-                    terminated(parse_operation, line_ending),
-                    terminated(parse_test, line_ending),
-                ),
-            ),
+            delimited(tag("Monkey "), u64, tag(":")),
+            pair(parse_starting_items, pair(parse_operation, parse_test)),
         ),
         |(queue, (op, test))| Monkey { queue, op, test },
     )(input)
@@ -198,7 +192,7 @@ fn parse_test(input: &str) -> IResult<&str, Test> {
 mod tests {
     use super::*;
 
-    static TEST_MONKEYS: &str = include_str!("../inputs/day11_test.txt");
+    static TEST_MONKEYS: &str = include_str!("../../inputs/2022/day11_test.txt");
 
     #[test]
     fn part_1_works() {
@@ -215,6 +209,16 @@ mod tests {
         assert_eq!(2713310158, play_game(&mut monkeys, 10000, |x| x % factor));
     }
 
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_MONKEYS) {
+            let mut monkeys = parse_monkeys(&input).unwrap();
+
+            assert_eq!(4, monkeys.len());
+            assert_eq!(10605, play_game(&mut monkeys, 20, |x| x / 3));
+        }
+    }
+
     #[test]
     fn parse_starting_items_works() {
         let input = "Starting items: 98, 89, 78";
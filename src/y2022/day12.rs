@@ -1,7 +1,16 @@
 use std::collections::VecDeque;
 
+use crate::image::{Image, Palette};
+use crate::registry::Params;
+
 pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
-    let elevation_map = ElevationMap::construct(input, 80, 41);
+    run_with(input, &Params::default())
+}
+
+/// Solves for a map of `width` by `height` squares, which default to the size of the real map.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(usize, usize)> {
+    let elevation_map =
+        ElevationMap::construct(input, params.get("width", 80)?, params.get("height", 41)?);
     let shortest_path = ShortestPath::new(&elevation_map);
 
     let part_1 = shortest_path.shortest_path(
@@ -20,6 +29,22 @@ pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     Ok((part_1, part_2))
 }
 
+/// Draws how many steps each square is from the start, following the rules of part 1, with the
+/// squares that can't be reached left black.
+pub fn draw(input: &str, params: &Params, palette: &dyn Palette) -> anyhow::Result<Image> {
+    let (width, height) = (params.get("width", 80)?, params.get("height", 41)?);
+    let elevation_map = ElevationMap::construct(input, width, height);
+    let distances = elevation_map.distances(elevation_map.start, |c, n| c + 1 >= n);
+
+    // Leave out the sentinel values around the edges
+    let steps: Vec<_> = (1..=height)
+        .flat_map(|y| (1..=width).map(move |x| y * elevation_map.width_adj + x))
+        .map(|pos| distances[pos].map(|steps| steps as f64))
+        .collect();
+
+    Ok(Image::from_values(width, height, &steps, palette))
+}
+
 struct ShortestPath<'a> {
     elevation_map: &'a ElevationMap,
 }
@@ -132,6 +157,34 @@ impl ElevationMap {
         }
     }
 
+    /// The number of steps to every square from `start`, if it can be reached.
+    fn distances<T>(&self, start: usize, can_traverse: T) -> Vec<Option<usize>>
+    where
+        T: Fn(u8, u8) -> bool,
+    {
+        let mut distances = vec![None; self.elevations.len()];
+        let mut queue = VecDeque::new();
+
+        distances[start] = Some(0);
+        queue.push_back((start, 0));
+
+        while let Some((pos, steps)) = queue.pop_front() {
+            let (adj_positions, elevations) = self.adjacent_squares(pos);
+
+            for i in 0..NUM_ADJACENT {
+                let next_pos = adj_positions[i];
+                if distances[next_pos].is_none()
+                    && can_traverse(self.elevations[pos], elevations[i])
+                {
+                    distances[next_pos] = Some(steps + 1);
+                    queue.push_back((next_pos, steps + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
     fn adjacent_squares(&self, position: usize) -> ([usize; NUM_ADJACENT], [u8; NUM_ADJACENT]) {
         let positions = [
             position - self.width_adj,
@@ -154,12 +207,7 @@ impl ElevationMap {
 mod tests {
     use super::*;
 
-    static TEST_MAP: &str = "\
-Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi";
+    static TEST_MAP: &str = include_str!("../../inputs/2022/day12_test.txt");
 
     #[test]
     fn part_1() {
@@ -188,4 +236,12 @@ abdefghi";
 
         assert_eq!(29, part_2);
     }
+
+    #[test]
+    fn distances_agree_with_part_1() {
+        let elevation_map = ElevationMap::construct(TEST_MAP, 8, 5);
+        let distances = elevation_map.distances(elevation_map.start, |c, n| c + 1 >= n);
+
+        assert_eq!(Some(31), distances[elevation_map.end]);
+    }
 }
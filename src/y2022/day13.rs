@@ -59,42 +59,40 @@ enum Item {
 }
 
 impl PartialOrd for Item {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Item::Int(a), Item::Int(b)) => Some(a.cmp(b)),
+            (Item::Int(a), Item::Int(b)) => a.cmp(b),
             (l @ Item::Int(_), Item::List(r)) => {
                 if r.items.is_empty() {
-                    Some(Ordering::Greater)
+                    Ordering::Greater
                 } else {
                     match l.cmp(&r.items[0]) {
-                        o if o == Ordering::Equal && r.items.len() > 1 => Some(Ordering::Less),
-                        o => Some(o),
+                        Ordering::Equal if r.items.len() > 1 => Ordering::Less,
+                        o => o,
                     }
                 }
             }
             (Item::List(l), r @ Item::Int(_)) => {
                 if l.items.is_empty() {
-                    Some(Ordering::Less)
+                    Ordering::Less
                 } else {
                     match r.cmp(&l.items[0]) {
-                        o if o == Ordering::Equal && l.items.len() > 1 => Some(Ordering::Greater),
-                        Ordering::Less => Some(Ordering::Greater),
-                        Ordering::Greater => Some(Ordering::Less),
-                        Ordering::Equal => Some(Ordering::Equal),
+                        Ordering::Equal if l.items.len() > 1 => Ordering::Greater,
+                        o => o.reverse(),
                     }
                 }
             }
-            (Item::List(l), Item::List(r)) => l.partial_cmp(r),
+            (Item::List(l), Item::List(r)) => l.cmp(r),
         }
     }
 }
 
-impl Ord for Item {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 struct Parser<'a> {
     input: Peekable<Chars<'a>>,
     eof: bool,
@@ -109,24 +107,24 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_pair(&mut self) -> (List, List) {
+        self.skip_whitespace();
         let l = self.parse_list();
-        self.expect_next('\n');
+        self.skip_whitespace();
         let r = self.parse_list();
 
-        if self.input.peek() == Some(&'\n') {
-            self.input.next();
-            if self.input.peek() == Some(&'\n') {
-                self.input.next();
-            } else {
-                self.eof = true;
-            }
-        } else {
+        // Line endings and any trailing whitespace between pairs are insignificant
+        self.skip_whitespace();
+        if self.input.peek().is_none() {
             self.eof = true;
         }
 
         (l, r)
     }
 
+    fn skip_whitespace(&mut self) {
+        while self.input.next_if(char::is_ascii_whitespace).is_some() {}
+    }
+
     // [[1],[2,3,4]]
     fn parse_list(&mut self) -> List {
         let mut list = List::new();
@@ -195,7 +193,7 @@ impl<'a> Iterator for Parser<'a> {
 mod tests {
     use super::*;
 
-    static TEST_PAIRS: &str = include_str!("../inputs/day13_test.txt");
+    static TEST_PAIRS: &str = include_str!("../../inputs/2022/day13_test.txt");
 
     #[test]
     fn parses_a_list() {
@@ -230,4 +228,11 @@ mod tests {
 
         assert_eq!(13, part_1);
     }
+
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_PAIRS) {
+            assert_eq!((13, 140), run(&input).unwrap());
+        }
+    }
 }
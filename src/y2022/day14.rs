@@ -0,0 +1,240 @@
+use anyhow::Context;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+use crate::image::{Image, Palette};
+use crate::registry::Params;
+use crate::viz::{Animation, Colour, Frame, Render};
+
+pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
+    let mut cave = Cave::build(input)?;
+
+    let drops = cave.fill_from_until((500, 0), |cave, settled_at| settled_at.1 > cave.ymax);
+    let more_drops = cave.fill_from_until((500, 0), |_, settled_at| settled_at == (500, 0));
+
+    // Take 1 off the drops because we're are dropping sand until it falls into the abyss, but
+    // we're counting how many drops there are _before_ it falls into the abyss
+    Ok((drops - 1, drops + more_drops))
+}
+
+/// Animates the sand of part 2 piling up on the floor, a grain at a time.
+pub fn visualise(input: &str, _: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let mut pile = Pile {
+        cave: Cave::build(input)?,
+        source: (500, 0),
+        last: None,
+        drops: 0,
+    };
+
+    animation.play(&mut pile, |pile| {
+        let settled_at = pile.cave.drop_sand(pile.source);
+        pile.last = Some(settled_at);
+        pile.drops += 1;
+
+        Ok(settled_at != pile.source)
+    })
+}
+
+/// Draws the pile of sand once it has blocked the source, colouring each grain by when it came to
+/// rest, on the rock and the floor.
+pub fn draw(input: &str, _: &Params, palette: &dyn Palette) -> anyhow::Result<Image> {
+    let mut cave = Cave::build(input)?;
+    let source = (500, 0);
+
+    let mut settled = HashMap::new();
+    loop {
+        let settled_at = cave.drop_sand(source);
+        settled.insert(settled_at, settled.len());
+        if settled_at == source {
+            break;
+        }
+    }
+
+    let floor = cave.ymax + 2;
+    let (xmin, xmax) = (source.0 - floor, source.0 + floor);
+    let (width, height) = ((xmax - xmin + 1) as usize, (floor + 1) as usize);
+
+    let mut drops = vec![None; width * height];
+    for (&(x, y), &drop) in &settled {
+        drops[y as usize * width + (x - xmin) as usize] = Some(drop as f64);
+    }
+    let mut image = Image::from_values(width, height, &drops, palette);
+
+    for (&(x, y), tile) in &cave.tiles {
+        if *tile == Tile::Rock {
+            image.set(((x - xmin) as usize, y as usize), Colour::GREY);
+        }
+    }
+    for x in 0..width {
+        image.set((x, floor as usize), Colour::GREY);
+    }
+
+    Ok(image)
+}
+
+struct Pile {
+    cave: Cave,
+    source: (i32, i32),
+    /// Where the last grain of sand came to rest
+    last: Option<(i32, i32)>,
+    drops: usize,
+}
+
+impl Render for Pile {
+    fn render(&self) -> Frame {
+        let floor = self.cave.ymax + 2;
+        // The pile can spread as far either side of the source as the floor is below it
+        let (xmin, xmax) = self
+            .cave
+            .tiles
+            .keys()
+            .map(|&(x, _)| x)
+            .chain([self.source.0 - floor, self.source.0 + floor])
+            .minmax()
+            .into_option()
+            .unwrap();
+
+        let mut frame = Frame::covering((xmin as i64, 0), (xmax as i64, floor as i64));
+        for (&(x, y), tile) in self.cave.tiles.iter() {
+            let (glyph, colour) = match tile {
+                Tile::Air => continue,
+                Tile::Rock => ('#', Colour::GREY),
+                Tile::Sand => ('o', Colour::YELLOW),
+            };
+            frame.set((x as i64, y as i64), glyph, Some(colour));
+        }
+        for x in xmin..=xmax {
+            frame.set((x as i64, floor as i64), '#', Some(Colour::GREY));
+        }
+        if self.cave.get_tile(self.source) == Tile::Air {
+            frame.set(
+                (self.source.0 as i64, self.source.1 as i64),
+                '+',
+                Some(Colour::WHITE),
+            );
+        }
+
+        if let Some((x, y)) = self.last {
+            frame.set((x as i64, y as i64), 'o', Some(Colour::ORANGE));
+            frame.set_focus((x as i64, y as i64));
+        }
+        frame.set_caption(format!("{} grains of sand", self.drops));
+
+        frame
+    }
+}
+
+struct Cave {
+    tiles: HashMap<(i32, i32), Tile>,
+    ymax: i32,
+}
+
+impl Cave {
+    fn fill_from_until<F>(&mut self, from: (i32, i32), mut pred: F) -> usize
+    where
+        F: FnMut(&Self, (i32, i32)) -> bool,
+    {
+        let mut drops = 0;
+
+        loop {
+            let settled_at = self.drop_sand(from);
+            drops += 1;
+
+            if pred(self, settled_at) {
+                return drops;
+            }
+        }
+    }
+
+    #[inline]
+    fn drop_sand(&mut self, (mut x, mut y): (i32, i32)) -> (i32, i32) {
+        'outer: loop {
+            let next_y = y + 1;
+
+            for next_x in [x, x - 1, x + 1] {
+                if self.get_tile((next_x, next_y)) == Tile::Air {
+                    (x, y) = (next_x, next_y);
+                    continue 'outer;
+                }
+            }
+
+            self.set_tile((x, y), Tile::Sand);
+            return (x, y);
+        }
+    }
+
+    fn get_tile(&self, coord: (i32, i32)) -> Tile {
+        if coord.1 >= self.ymax + 2 {
+            Tile::Rock
+        } else {
+            self.tiles.get(&coord).copied().unwrap_or(Tile::Air)
+        }
+    }
+
+    fn set_tile(&mut self, coord: (i32, i32), tile: Tile) {
+        self.tiles.insert(coord, tile);
+    }
+
+    fn build(input: &str) -> anyhow::Result<Self> {
+        let mut ymax = 0;
+        let mut tiles = HashMap::new();
+
+        for line in input.lines() {
+            for (a, b) in line.split(" -> ").tuple_windows() {
+                let (xa, ya) = a.split_once(',').context("Invalid input")?;
+                let (xa, ya): (i32, i32) = (xa.parse()?, ya.parse()?);
+
+                let (xb, yb) = b.split_once(',').context("Invalid input")?;
+                let (xb, yb): (i32, i32) = (xb.parse()?, yb.parse()?);
+
+                if ya == yb {
+                    let start = xa.min(xb);
+                    let end = xa.max(xb);
+                    for x in start..=end {
+                        tiles.insert((x, ya), Tile::Rock);
+                    }
+                } else if xa == xb {
+                    let start = ya.min(yb);
+                    let end = ya.max(yb);
+                    for y in start..=end {
+                        tiles.insert((xa, y), Tile::Rock);
+                    }
+                }
+
+                ymax = ya.max(yb).max(ymax);
+            }
+        }
+
+        Ok(Self { tiles, ymax })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day14_test.txt");
+
+    #[test]
+    fn part_1_works() {
+        let mut cave = Cave::build(TEST_INPUT).unwrap();
+
+        let drops = cave.fill_from_until((500, 0), |cave, settled_at| settled_at.1 > cave.ymax) - 1;
+        assert_eq!(24, drops);
+    }
+
+    #[test]
+    fn part_2_works() {
+        let mut cave = Cave::build(TEST_INPUT).unwrap();
+
+        let drops = cave.fill_from_until((500, 0), |_, settled_at| settled_at == (500, 0));
+        assert_eq!(93, drops);
+    }
+}
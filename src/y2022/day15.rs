@@ -6,22 +6,27 @@ use nom::multi::many0;
 use nom::sequence::{preceded, separated_pair, terminated};
 use nom::IResult;
 
+use crate::registry::Params;
+
 pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
+    run_with(input, &Params::default())
+}
+
+/// Solves for the `row` to count the covered positions of and the `bound` of the distress
+/// beacon's coordinates, which default to those of the real puzzle.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(i64, i64)> {
     let sensors = parse_sensors(input)?;
+    let (row, bound) = (
+        params.get("row", 2_000_000)?,
+        params.get("bound", 4_000_000)?,
+    );
 
     let mut segments = Vec::new();
-    covered_segments(&sensors, 2000000, &mut segments);
+    covered_segments(&sensors, row, &mut segments);
     let part_1 = total_extent(&segments);
 
-    let beacon = find_beacon(
-        &sensors,
-        Coordinate {
-            x: 4_000_000,
-            y: 4_000_000,
-        },
-        &mut segments,
-    )
-    .context("No beacon found")?;
+    let beacon = find_beacon(&sensors, Coordinate { x: bound, y: bound }, &mut segments)
+        .context("No beacon found")?;
     let frequency = 4_000_000 * beacon.x + beacon.y;
 
     Ok((part_1, frequency))
@@ -115,7 +120,7 @@ fn parse_sensor(input: &str) -> IResult<&str, (Coordinate, Coordinate)> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    static TEST_INPUT: &str = include_str!("../inputs/day15_test.txt");
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day15_test.txt");
 
     #[test]
     fn parse_sensor_works() {
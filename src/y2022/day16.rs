@@ -7,6 +7,7 @@ use nom::character::complete::{alpha1, i32, line_ending};
 use nom::multi::{many0, separated_list0};
 use nom::sequence::{preceded, terminated, tuple};
 use nom::IResult;
+use tracing::debug;
 
 pub fn run(input: &str) -> anyhow::Result<(i32, i32)> {
     let cave: Cave<5> = input.parse()?;
@@ -20,6 +21,7 @@ fn cave_run<const E: usize>(cave: &Cave<E>, time_limit: i32, starting_states: St
     let state_space = starting_states.state_space;
     let valve_count = cave.valve_count();
     let mut states = starting_states;
+    debug!(valve_count, state_space, time_limit, "running cave");
 
     for minute in 0..time_limit {
         let mut next_states = States::new(valve_count, state_space);
@@ -274,7 +276,7 @@ impl<const E: usize> Edges<E> {
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = include_str!("../inputs/day16_test.txt");
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day16_test.txt");
 
     #[test]
     fn part_1_works() {
@@ -1,6 +1,8 @@
-use std::fmt::Display;
-
 use itertools::Itertools;
+use tracing::debug;
+
+use crate::registry::Params;
+use crate::viz::{Animation, Colour, Frame, Render};
 
 pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
     let jets: Vec<Jet> = input
@@ -28,6 +30,7 @@ pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
     }
 
     let period = cycle_detection(&deltas, 5000).unwrap();
+    debug!(period, "found cycle in height deltas");
 
     let n = (1_000_000_000_000 - 2022) / period;
     let rem = (1_000_000_000_000 - 2022) % period;
@@ -40,11 +43,28 @@ pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
     Ok((part_1, part_2))
 }
 
+/// Animates the first 2022 rocks falling, a rock at a time.
+pub fn visualise(input: &str, _: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let jets: Vec<Jet> = input
+        .trim()
+        .bytes()
+        .map(|b| b.try_into())
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut jets = jets.into_iter().cycle();
+    let mut rocks = 0..2022;
+
+    animation.play(&mut Chamber::<7>::new(), |chamber| {
+        Ok(rocks
+            .next()
+            .map(|i| chamber.drop_rock(i, &mut jets))
+            .is_some())
+    })
+}
+
 fn cycle_detection(deltas: &[i64], upper_bound: usize) -> Option<usize> {
     (1..=upper_bound).find(|&period| {
-        (0..period)
-            .map(|offset| deltas.iter().skip(offset).step_by(period).all_equal())
-            .all(|b| b)
+        (0..period).all(|offset| deltas.iter().skip(offset).step_by(period).all_equal())
     })
 }
 
@@ -53,16 +73,36 @@ struct Chamber<const WIDTH: usize> {
     height: i64,
 }
 
-impl<const WIDTH: usize> Display for Chamber<WIDTH> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.tiles.chunks(WIDTH).take(self.height as usize).rev() {
-            for jet in row.iter() {
-                write!(f, "{}", jet)?;
+impl<const WIDTH: usize> Render for Chamber<WIDTH> {
+    fn render(&self) -> Frame {
+        // Up is positive y in the chamber, but downwards for frames, with the floor at y = 1
+        let top = -(self.height + 3);
+        let mut frame = Frame::covering((-1, top), (WIDTH as i64, 1));
+
+        for y in top..=1 {
+            frame.set((-1, y), '|', Some(Colour::GREY));
+            frame.set((WIDTH as i64, y), '|', Some(Colour::GREY));
+        }
+        for x in -1..=WIDTH as i64 {
+            frame.set((x, 1), '-', Some(Colour::GREY));
+        }
+        for (y, row) in self
+            .tiles
+            .chunks(WIDTH)
+            .take(self.height as usize)
+            .enumerate()
+        {
+            for (x, tile) in row.iter().enumerate() {
+                if *tile == Tile::Rock {
+                    frame.set((x as i64, -(y as i64)), '#', Some(Colour::CYAN));
+                }
             }
-            writeln!(f)?;
         }
 
-        Ok(())
+        frame.set_focus((0, top));
+        frame.set_caption(format!("height {}", self.height));
+
+        frame
     }
 }
 
@@ -141,15 +181,6 @@ enum Tile {
     Rock,
 }
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Tile::Air => f.write_str("."),
-            Tile::Rock => f.write_str("#"),
-        }
-    }
-}
-
 struct Rock {
     pos: (i64, i64),
     /// Bounding box
@@ -8,6 +8,10 @@ use nom::multi::separated_list0;
 use nom::sequence::{preceded, tuple};
 use nom::IResult;
 
+use crate::image::{Image, Palette};
+use crate::registry::Params;
+use crate::viz::Colour;
+
 pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     let cubes = parse_lines(input)?;
     let flooded = flood_fill(&cubes);
@@ -37,6 +41,54 @@ pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     Ok((part_1, part_2))
 }
 
+/// Draws the droplet a slice at a time, from its lowest z to its highest, with the lava coloured by
+/// its z and the air trapped inside it in red.
+pub fn draw(input: &str, _: &Params, palette: &dyn Palette) -> anyhow::Result<Image> {
+    let cubes = parse_lines(input)?;
+    let flooded = flood_fill(&cubes);
+
+    let (min, max) = cubes
+        .iter()
+        .fold(None, |bounds: Option<(Point, Point)>, &cube| match bounds {
+            Some((min, max)) => Some((min.min(cube), max.max(cube))),
+            None => Some((cube, cube)),
+        })
+        .ok_or_else(|| anyhow::anyhow!("there are no cubes"))?;
+
+    let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+    let slices = (max.z - min.z + 1) as usize;
+    // Lay the slices out in rows, with a pixel between each of them
+    let columns = (slices as f64).sqrt().ceil() as usize;
+    let rows = slices.div_ceil(columns);
+    let mut image = Image::new(
+        columns * (width + 1) - 1,
+        rows * (height + 1) - 1,
+        Colour::GREY,
+    );
+
+    for (i, z) in (min.z..=max.z).enumerate() {
+        let mut slice = Image::new(width, height, Colour::BLACK);
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let point = Point { x, y, z };
+                let colour = if cubes.contains(&point) {
+                    palette.colour(i as f64 / (slices - 1).max(1) as f64)
+                } else if !flooded.contains(&point) {
+                    Colour::RED
+                } else {
+                    continue;
+                };
+                slice.set(((x - min.x) as usize, (y - min.y) as usize), colour);
+            }
+        }
+
+        let (column, row) = (i % columns, i / columns);
+        image.paste(&slice, (column * (width + 1), row * (height + 1)));
+    }
+
+    Ok(image)
+}
+
 const DIFFS: [Point; 6] = [
     Point { x: -1, y: 0, z: 0 },
     Point { x: 1, y: 0, z: 0 },
@@ -155,7 +207,7 @@ fn parse_lines(input: &str) -> anyhow::Result<Cubes> {
 mod tests {
     use super::*;
 
-    static TEST_CUBES: &str = include_str!("../inputs/day18_test.txt");
+    static TEST_CUBES: &str = include_str!("../../inputs/2022/day18_test.txt");
 
     #[test]
     fn it_works() {
@@ -7,6 +7,7 @@ use nom::multi::separated_list0;
 use nom::sequence::{delimited, preceded, separated_pair, tuple};
 use nom::IResult;
 use rayon::prelude::*;
+use tracing::{debug, debug_span};
 
 pub fn run(input: &str) -> anyhow::Result<(u32, u32)> {
     let blueprints = parse_blueprints(input)?;
@@ -14,12 +15,20 @@ pub fn run(input: &str) -> anyhow::Result<(u32, u32)> {
     let part_1: u32 = blueprints
         .par_iter()
         .enumerate()
-        .map(|(i, blueprint)| (i as u32 + 1) * max(blueprint, 24) as u32)
+        .map(|(i, blueprint)| {
+            let _span = debug_span!("blueprint", id = i + 1).entered();
+            (i as u32 + 1) * max(blueprint, 24) as u32
+        })
         .sum();
 
-    let part_2: u32 = blueprints[0..3]
+    // Only the first three blueprints are left, and the example only has two
+    let part_2: u32 = blueprints[..blueprints.len().min(3)]
         .par_iter()
-        .map(|blueprint| max(blueprint, 32) as u32)
+        .enumerate()
+        .map(|(i, blueprint)| {
+            let _span = debug_span!("blueprint", id = i + 1).entered();
+            max(blueprint, 32) as u32
+        })
         .product();
 
     Ok((part_1, part_2))
@@ -88,7 +97,7 @@ impl Resources {
             .map(|(i, cost)| match self.robots[i] {
                 0 => None,
                 _ if self.materials[i] >= *cost => Some(0),
-                n => Some((cost - self.materials[i] + n - 1) / n),
+                n => Some((cost - self.materials[i]).div_ceil(n)),
             })
         {
             let cost = cost?;
@@ -116,9 +125,11 @@ fn max(blueprint: &Blueprint, time_limit: u16) -> u16 {
     let mut stack: Vec<(u16, Resources)> = Vec::new();
     stack.push((0, resources));
     let mut max_geodes = 0;
+    let (mut nodes, mut pruned) = (0u64, 0u64);
 
     while let Some((time, resources)) = stack.pop() {
         let time_left = time_limit - time;
+        nodes += 1;
 
         max_geodes = max_geodes.max(resources.materials[GEODE]);
 
@@ -135,6 +146,7 @@ fn max(blueprint: &Blueprint, time_limit: u16) -> u16 {
                         let time_left = time_left - wait - 1;
 
                         if resources.robots[idx] > max_material_costs[idx] {
+                            pruned += 1;
                             continue;
                         }
 
@@ -145,6 +157,7 @@ fn max(blueprint: &Blueprint, time_limit: u16) -> u16 {
                                 + 1
                                 < max_geodes
                         {
+                            pruned += 1;
                             continue;
                         }
 
@@ -155,6 +168,8 @@ fn max(blueprint: &Blueprint, time_limit: u16) -> u16 {
         }
     }
 
+    debug!(time_limit, nodes, pruned, max_geodes, "searched blueprint");
+
     max_geodes
 }
 
@@ -230,4 +245,11 @@ mod tests {
 
         assert_eq!(12, max(&blueprint, 24));
     }
+
+    #[test]
+    fn part_2_works_with_fewer_than_three_blueprints() {
+        let input = include_str!("../../inputs/2022/day19_test.txt");
+
+        assert_eq!((33, 56 * 62), run(input).unwrap());
+    }
 }
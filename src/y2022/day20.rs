@@ -1,3 +1,7 @@
+use std::io::BufRead;
+
+use crate::utils::for_each_line;
+
 pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
     let input: Vec<i64> = input
         .lines()
@@ -10,6 +14,23 @@ pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
     Ok((part_1, part_2))
 }
 
+/// Streaming variant of `run`, which avoids holding the text of the input in memory. Mixing needs
+/// random access to every number, so the numbers themselves are still collected.
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(i64, i64)> {
+    let mut input = Vec::new();
+    for_each_line(reader, |line| {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        input.push(line.parse()?);
+        Ok(())
+    })?;
+
+    Ok((mix(&input, 1, 1), mix(&input, 10, 811589153)))
+}
+
 fn mix(nums: &[i64], rounds: usize, key: i64) -> i64 {
     let nums = nums.iter().map(|x| x * key).collect::<Vec<_>>();
     let mut ans = (0..nums.len()).collect::<Vec<_>>();
@@ -28,3 +49,16 @@ fn mix(nums: &[i64], rounds: usize, key: i64) -> i64 {
         .map(|i| nums[ans[(i2 + i) % ans.len()]])
         .sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day20_test.txt");
+
+    #[test]
+    fn it_works() {
+        assert_eq!((3, 1623178306), run(TEST_INPUT).unwrap());
+        assert_eq!((3, 1623178306), run_stream(TEST_INPUT.as_bytes()).unwrap());
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 use std::ops;
 
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use nom::branch::alt;
@@ -11,37 +11,24 @@ use nom::combinator::{map, value};
 use nom::multi::separated_list0;
 use nom::sequence::{terminated, tuple};
 use nom::IResult;
+use tracing::{debug, trace};
 
 use crate::utils::ws;
 
 pub fn run(input: &str) -> anyhow::Result<(i64, i64)> {
-    let mut tree = MonkeyTree::build_tree(input)?;
+    let tree = MonkeyTree::build_tree(input)?;
 
     let root_idx = *tree.names.get("root").unwrap();
     let humn_idx = *tree.names.get("humn").unwrap();
 
-    let path = find_path(root_idx, humn_idx, &tree);
-
     let part_1 = get_value(root_idx, &tree);
     let mut cached_values = vec![0; tree.monkeys.len()];
     evaluate_tree(root_idx, &mut cached_values, &tree);
 
-    let balanced = balance_values(root_idx, humn_idx, &tree, &cached_values);
-    dbg!(balanced);
-
-    let mut part_2 = 0;
-
-    if let Yell::Maths(m) = tree.monkeys[root_idx] {
-        let (_, b) = (get_value(m.monkeys.0, &tree), get_value(m.monkeys.1, &tree));
-
-        part_2 = search(b, m.monkeys.0, humn_idx, &mut tree);
-
-        tree.monkeys[humn_idx] = Yell::Value(part_2);
-        let (a, b) = (get_value(m.monkeys.0, &tree), get_value(m.monkeys.1, &tree));
-        dbg!(a, b);
-    } else {
-        anyhow::bail!("Error");
-    };
+    // Each operation is inverted exactly, as truncating division lets other values balance too,
+    // e.g. 302 as well as 301 in the example
+    let part_2 = balance_values(root_idx, humn_idx, &tree, &cached_values);
+    debug!(part_2, "balanced humn by walking down from root");
 
     Ok((part_1, part_2))
 }
@@ -143,6 +130,7 @@ fn get_value(idx: MonkeyIdx, graph: &MonkeyTree) -> i64 {
     }
 }
 
+#[allow(dead_code)]
 fn is_tree(graph: &MonkeyTree) -> bool {
     let root_idx = *graph.names.get("root").unwrap();
     let mut visited = HashSet::new();
@@ -162,42 +150,11 @@ fn is_tree(graph: &MonkeyTree) -> bool {
         }
     }
 
-    dbg!(visited.len());
+    trace!(visited = visited.len(), "monkeys form a tree");
 
     true
 }
 
-fn search(target: i64, idx: MonkeyIdx, humn_idx: MonkeyIdx, graph: &mut MonkeyTree) -> i64 {
-    let mut value: i64 = 0;
-    let mut magnitude: i64 = 2 << 48;
-
-    while magnitude > 0 {
-        graph.monkeys[humn_idx] = Yell::Value(value - magnitude);
-        let neg_delta = (target - get_value(idx, graph)).abs();
-
-        if neg_delta == 0 {
-            return value - magnitude;
-        }
-
-        graph.monkeys[humn_idx] = Yell::Value(value + magnitude);
-        let pos_delta = (target - get_value(idx, graph)).abs();
-
-        if pos_delta == 0 {
-            return value + magnitude;
-        }
-
-        if pos_delta < neg_delta {
-            value += magnitude;
-        } else {
-            value -= magnitude;
-        }
-
-        magnitude /= 2;
-    }
-
-    value
-}
-
 type MonkeyIdx = usize;
 
 struct MonkeyTree<'a> {
@@ -265,7 +222,7 @@ impl<'a> MonkeyTree<'a> {
             Maths((&'a str, Op, &'a str)),
         }
 
-        fn parse_line(input: &str) -> IResult<&str, (&str, ParsedYell)> {
+        fn parse_line(input: &str) -> IResult<&str, (&str, ParsedYell<'_>)> {
             tuple((
                 terminated(alpha0, tag(": ")),
                 alt((
@@ -320,22 +277,7 @@ impl<'a> MonkeyTree<'a> {
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = "\
-root: pppw + sjmn
-dbpl: 5
-cczh: sllz + lgvd
-zczc: 2
-ptdq: humn - dvpt
-dvpt: 3
-lfqf: 4
-humn: 5
-ljgn: 2
-sjmn: drzm * dbpl
-sllz: 4
-pppw: cczh / lfqf
-lgvd: ljgn * ptdq
-drzm: hmdt - zczc
-hmdt: 32";
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day21_test.txt");
 
     #[test]
     fn it_works() {
@@ -345,4 +287,34 @@ hmdt: 32";
         let part_1 = get_value(root_idx, &monkey_graph);
         assert_eq!(152, part_1);
     }
+
+    #[test]
+    fn part_2_is_the_exact_balance() {
+        // Truncating division balances root for 302 as well, as 602 / 4 is 150 just like 600 / 4
+        assert_eq!((152, 301), run(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn balances_humn_on_either_branch() {
+        let generator = crate::y2022::generate::find("day21").unwrap();
+
+        for seed in 0..20 {
+            let input = generator.seeded(seed, 200);
+            let mut tree = MonkeyTree::build_tree(&input).unwrap();
+
+            let root_idx = *tree.names.get("root").unwrap();
+            let humn_idx = *tree.names.get("humn").unwrap();
+
+            let mut cached_values = vec![0; tree.monkeys.len()];
+            evaluate_tree(root_idx, &mut cached_values, &tree);
+            let balanced = balance_values(root_idx, humn_idx, &tree, &cached_values);
+
+            tree.monkeys[humn_idx] = Yell::Value(balanced);
+            let Yell::Maths(m) = tree.monkeys[root_idx] else {
+                panic!("Invalid root");
+            };
+
+            assert_eq!(get_value(m.monkeys.0, &tree), get_value(m.monkeys.1, &tree));
+        }
+    }
 }
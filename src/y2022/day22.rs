@@ -1,15 +1,24 @@
-use anyhow::Context;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, u32};
-use nom::combinator::{map, value};
-use nom::multi::many_till;
+use nom::character::complete::u32;
+use nom::combinator::{all_consuming, map, value};
+use nom::multi::many1;
 use nom::IResult;
 
+use crate::registry::Params;
+use crate::utils::sections;
+
 pub fn run(input: &str) -> anyhow::Result<(isize, isize)> {
-    let (grid, moves) = input.split_once("\n\n").context("Invalid input")?;
+    run_with(input, &Params::default())
+}
 
-    let grid = Grid::build_grid(grid, 150)?;
+/// Solves for a map `columns` wide, which defaults to the width of the real map.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(isize, isize)> {
+    let [grid, moves] = sections(input)[..] else {
+        anyhow::bail!("Invalid input");
+    };
+
+    let grid = Grid::build_grid(grid, params.get("columns", 150)?)?;
     let moves = parse_moves(moves)?;
 
     let part_1 = play(&grid, &moves);
@@ -234,7 +243,8 @@ impl Grid {
 
         for line in input.lines() {
             rows += 1;
-            let bytes = line.as_bytes();
+            // Trailing whitespace would only be padded with out of bounds tiles anyway
+            let bytes = line.trim_end().as_bytes();
             let len = bytes.len();
 
             if bytes.len() > columns {
@@ -273,7 +283,7 @@ fn parse_moves(input: &str) -> anyhow::Result<Vec<Move>> {
         ))(input)
     }
 
-    let (_, (moves, _)) = many_till(parse_move, line_ending)(input)
+    let (_, moves) = all_consuming(many1(parse_move))(input.trim())
         .map_err(|_| anyhow::anyhow!("error parsing moves"))?;
 
     Ok(moves)
@@ -283,7 +293,7 @@ fn parse_moves(input: &str) -> anyhow::Result<Vec<Move>> {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day22_test.txt");
+    const TEST_INPUT: &str = include_str!("../../inputs/2022/day22_test.txt");
 
     #[test]
     fn part_1_works() {
@@ -296,4 +306,18 @@ mod tests {
 
         assert_eq!(6032, part_1);
     }
+
+    #[test]
+    fn handles_input_variants() {
+        for input in crate::input::variants(TEST_INPUT) {
+            let [grid, moves] = sections(&input)[..] else {
+                panic!("expected a grid and moves");
+            };
+
+            let grid = Grid::build_grid(grid, 16).unwrap();
+            let moves = parse_moves(moves).unwrap();
+
+            assert_eq!(6032, play(&grid, &moves));
+        }
+    }
 }
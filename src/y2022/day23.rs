@@ -1,17 +1,79 @@
 use itertools::{Itertools, MinMaxResult};
 use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::{debug, debug_span};
+
+use crate::image::{Image, Palette};
+use crate::registry::Params;
+use crate::viz::{Animation, Colour, Frame, Render};
 
 pub fn run(input: &str) -> anyhow::Result<(usize, usize)> {
     let mut grid = Elves::build_grid(input);
 
     let part_1 = grid.simulate(10);
-    let now = std::time::Instant::now();
-    let part_2 = grid.simulate_until_stopped();
-    dbg!(now.elapsed());
+    let part_2 = debug_span!("simulate_until_stopped").in_scope(|| grid.simulate_until_stopped());
+    debug!(
+        elves = grid.grid.len(),
+        rounds = part_2,
+        "elves stopped moving"
+    );
 
     Ok((part_1, part_2))
 }
 
+/// Animates the elves spreading out until they stop moving, a round at a time.
+pub fn visualise(input: &str, _: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let mut elves = Elves::build_grid(input);
+    if elves.grid.is_empty() {
+        anyhow::bail!("there are no elves");
+    }
+    let mut moves_buffer = Vec::new();
+    let mut location_count = FxHashMap::default();
+
+    animation.play(&mut elves, |elves| {
+        Ok(elves.move_elves(&mut moves_buffer, &mut location_count))
+    })
+}
+
+/// Draws how the elves spread out, colouring each tile by how many rounds an elf stood on it, on a
+/// log scale so that the paths they took show up beside where they stopped.
+pub fn draw(input: &str, _: &Params, palette: &dyn Palette) -> anyhow::Result<Image> {
+    let mut elves = Elves::build_grid(input);
+    let mut moves_buffer = Vec::new();
+    let mut location_count = FxHashMap::default();
+
+    let mut rounds: FxHashMap<(i32, i32), usize> = FxHashMap::default();
+    loop {
+        for &elf in &elves.grid {
+            *rounds.entry(elf).or_default() += 1;
+        }
+        if !elves.move_elves(&mut moves_buffer, &mut location_count) {
+            break;
+        }
+    }
+
+    let (xmin, xmax) = rounds
+        .keys()
+        .map(|&(x, _)| x)
+        .minmax()
+        .into_option()
+        .ok_or_else(|| anyhow::anyhow!("there are no elves"))?;
+    let (ymin, ymax) = rounds
+        .keys()
+        .map(|&(_, y)| y)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (width, height) = ((xmax - xmin + 1) as usize, (ymax - ymin + 1) as usize);
+
+    // North is positive y for the elves, but the top of the image is its first row
+    let mut values = vec![None; width * height];
+    for (&(x, y), &count) in &rounds {
+        values[(ymax - y) as usize * width + (x - xmin) as usize] = Some((count as f64).ln());
+    }
+
+    Ok(Image::from_values(width, height, &values, palette))
+}
+
 struct Elves {
     grid: FxHashSet<(i32, i32)>,
     round: usize,
@@ -145,6 +207,41 @@ impl Elves {
     }
 }
 
+impl Render for Elves {
+    fn render(&self) -> Frame {
+        // North is positive y for the elves, but negative for frames
+        let point = |&(x, y): &(i32, i32)| (x as i64, -y as i64);
+        let (xmin, xmax) = self
+            .grid
+            .iter()
+            .map(|&(x, _)| x)
+            .minmax()
+            .into_option()
+            .unwrap();
+        let (ymin, ymax) = self
+            .grid
+            .iter()
+            .map(|&(_, y)| y)
+            .minmax()
+            .into_option()
+            .unwrap();
+
+        let mut frame = Frame::covering(point(&(xmin, ymax)), point(&(xmax, ymin)));
+        for elf in &self.grid {
+            frame.set(point(elf), '#', Some(Colour::GREEN));
+        }
+
+        frame.set_focus(point(&((xmin + xmax) / 2, (ymin + ymax) / 2)));
+        frame.set_caption(format!(
+            "round {}, {} empty ground tiles",
+            self.round,
+            self.count_empty()
+        ));
+
+        frame
+    }
+}
+
 // The last check for each direction is where the elf will want to move
 const CONSIDERATIONS: [[(i32, i32); 3]; 4] = [
     [(-1, 1), (1, 1), (0, 1)],    // North
@@ -157,7 +254,7 @@ const CONSIDERATIONS: [[(i32, i32); 3]; 4] = [
 mod tests {
     use super::*;
 
-    const TEST_ELVES: &str = include_str!("../inputs/day23_test.txt");
+    const TEST_ELVES: &str = include_str!("../../inputs/2022/day23_test.txt");
 
     #[test]
     fn it_works() {
@@ -166,4 +263,17 @@ mod tests {
         assert_eq!(110, part_1);
         assert_eq!(20, part_2);
     }
+
+    #[test]
+    fn visualising_no_elves_fails() {
+        let mut out = Vec::new();
+        let speed = crate::viz::Speed {
+            fps: 0.0,
+            steps_per_frame: 1,
+        };
+        let mut animation = Animation::new(&mut out, speed, (80, 24), false);
+
+        assert!(visualise("...\n...\n", &Params::default(), &mut animation).is_err());
+        assert_eq!(0, animation.frames());
+    }
 }
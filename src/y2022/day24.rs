@@ -0,0 +1,284 @@
+use std::collections::VecDeque;
+
+use anyhow::Context;
+use hashbrown::HashSet;
+use tracing::debug;
+
+use crate::registry::Params;
+use crate::viz::{Animation, Colour, Frame, Render};
+
+pub fn run(input: &str) -> anyhow::Result<(i32, i32)> {
+    run_with(input, &Params::default())
+}
+
+/// Solves for a valley whose interior is `width` by `height`, which default to the size of the
+/// real valley.
+pub fn run_with(input: &str, params: &Params) -> anyhow::Result<(i32, i32)> {
+    let valley = Valley::build_valley(input, params.get("width", 100)?, params.get("height", 35)?);
+
+    let entrance = (0, -1);
+    let exit = (valley.width - 1, valley.height);
+
+    let part_1 = traverse_valley(&valley, entrance, 0, exit).context("No way across")?;
+    let back = traverse_valley(&valley, exit, part_1, entrance).context("No way back")?;
+    let part_2 = traverse_valley(&valley, entrance, back, exit).context("No way across again")?;
+
+    Ok((part_1, part_2))
+}
+
+/// The time at which we can first reach `end` from `start`, where `start` is just outside the
+/// valley, so we can wait there for as long as we like.
+fn traverse_valley(valley: &Valley, start: (i32, i32), time: i32, end: (i32, i32)) -> Option<i32> {
+    let mut visited: HashSet<((i32, i32), i32)> = HashSet::new();
+    let mut queue: VecDeque<((i32, i32), i32)> = VecDeque::new();
+    queue.push_back((start, time));
+
+    while let Some((position, time)) = queue.pop_front() {
+        if visited.contains(&(position, time)) {
+            continue;
+        }
+
+        visited.insert((position, time));
+
+        if position == end {
+            debug!(visited = visited.len(), time, "crossed the valley");
+            return Some(time);
+        }
+
+        if position != start {
+            if position.0 < 0
+                || position.0 >= valley.width
+                || position.1 < 0
+                || position.1 >= valley.height
+            {
+                continue;
+            }
+
+            if valley.blizzards_at(position, time) > 0 {
+                continue;
+            }
+        }
+
+        for mov in MOVES {
+            let new_position = (position.0 + mov.0, position.1 + mov.1);
+            queue.push_back((new_position, time + 1));
+        }
+    }
+
+    None
+}
+
+/// Animates every position the expedition could be in as it crosses the valley, goes back for the
+/// snacks and crosses again, a minute at a time.
+pub fn visualise(input: &str, params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+    let valley = Valley::build_valley(input, params.get("width", 100)?, params.get("height", 35)?);
+    let entrance = (0, -1);
+    let exit = (valley.width - 1, valley.height);
+
+    let mut expedition = Expedition {
+        valley,
+        time: 0,
+        start: entrance,
+        reachable: HashSet::from([entrance]),
+        // In reverse, as they're popped off the end
+        goals: vec![exit, entrance, exit],
+    };
+
+    animation.play(&mut expedition, |expedition| expedition.step())
+}
+
+struct Expedition {
+    valley: Valley,
+    time: i32,
+    /// Where the current leg started, just outside the valley, where we can wait safely
+    start: (i32, i32),
+    /// Every position that we could be in at `time`
+    reachable: HashSet<(i32, i32)>,
+    /// The ends of the legs still to go, the current one last
+    goals: Vec<(i32, i32)>,
+}
+
+impl Expedition {
+    /// Advances a minute, returning whether there are any legs left to go.
+    fn step(&mut self) -> anyhow::Result<bool> {
+        let Some(&goal) = self.goals.last() else {
+            return Ok(false);
+        };
+
+        self.time += 1;
+        let valley = &self.valley;
+        let (start, time) = (self.start, self.time);
+        self.reachable = self
+            .reachable
+            .iter()
+            .flat_map(|position| MOVES.map(|mov| (position.0 + mov.0, position.1 + mov.1)))
+            .filter(|&position| {
+                position == start
+                    || position == goal
+                    || (position.0 >= 0
+                        && position.0 < valley.width
+                        && position.1 >= 0
+                        && position.1 < valley.height
+                        && valley.blizzards_at(position, time) == 0)
+            })
+            .collect();
+
+        if self.reachable.is_empty() {
+            anyhow::bail!("No way to {:?}", goal);
+        }
+        if self.reachable.contains(&goal) {
+            self.goals.pop();
+            self.start = goal;
+            self.reachable = HashSet::from([goal]);
+        }
+
+        Ok(!self.goals.is_empty())
+    }
+}
+
+impl Render for Expedition {
+    fn render(&self) -> Frame {
+        let valley = &self.valley;
+        let (width, height) = (valley.width as i64, valley.height as i64);
+        let mut frame = Frame::covering((-1, -1), (width, height));
+
+        for x in -1..=width {
+            frame.set((x, -1), '#', Some(Colour::GREY));
+            frame.set((x, height), '#', Some(Colour::GREY));
+        }
+        for y in -1..=height {
+            frame.set((-1, y), '#', Some(Colour::GREY));
+            frame.set((width, y), '#', Some(Colour::GREY));
+        }
+        frame.set((0, -1), ' ', None);
+        frame.set((width - 1, height), ' ', None);
+
+        let mut counts = vec![0u32; (width * height) as usize];
+        let mut blizzard = |position: (i64, i64), glyph: char| {
+            let count = &mut counts[(position.1 * width + position.0) as usize];
+            *count += 1;
+            let glyph = match *count {
+                1 => glyph,
+                count => char::from_digit(count.min(9), 10).unwrap(),
+            };
+            frame.set(position, glyph, Some(Colour::CYAN));
+        };
+        for (x, blizzards) in valley.x_lines.iter().enumerate() {
+            for b in blizzards {
+                let y = (b.initial + self.time * b.movement).rem_euclid(valley.height);
+                blizzard((x as i64, y as i64), if b.movement > 0 { 'v' } else { '^' });
+            }
+        }
+        for (y, blizzards) in valley.y_lines.iter().enumerate() {
+            for b in blizzards {
+                let x = (b.initial + self.time * b.movement).rem_euclid(valley.width);
+                blizzard((x as i64, y as i64), if b.movement > 0 { '>' } else { '<' });
+            }
+        }
+
+        for &(x, y) in &self.reachable {
+            frame.set((x as i64, y as i64), 'E', Some(Colour::GREEN));
+        }
+
+        if let Some(&(x, y)) = self.reachable.iter().next() {
+            frame.set_focus((x as i64, y as i64));
+        }
+        frame.set_caption(format!(
+            "minute {}, {} positions reachable",
+            self.time,
+            self.reachable.len()
+        ));
+
+        frame
+    }
+}
+
+const MOVES: [(i32, i32); 5] = [(1, 0), (-1, 0), (0, 1), (0, -1), (0, 0)];
+
+struct Valley {
+    width: i32,
+    height: i32,
+    x_lines: Vec<Vec<Blizzard>>,
+    y_lines: Vec<Vec<Blizzard>>,
+}
+
+impl Valley {
+    fn blizzards_at(&self, location: (i32, i32), time: i32) -> usize {
+        let count_y = self.x_lines[location.0 as usize]
+            .iter()
+            .map(|b| (b.initial + time * b.movement).rem_euclid(self.height))
+            .filter(|&y| y == location.1)
+            .count();
+
+        let count_x = self.y_lines[location.1 as usize]
+            .iter()
+            .map(|b| (b.initial + time * b.movement).rem_euclid(self.width))
+            .filter(|&x| x == location.0)
+            .count();
+
+        count_x + count_y
+    }
+
+    fn build_valley(input: &str, width: i32, height: i32) -> Self {
+        let mut x_lines: Vec<Vec<Blizzard>> = vec![Vec::default(); width as usize];
+        let mut y_lines: Vec<Vec<Blizzard>> = vec![Vec::default(); height as usize];
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, b) in line.bytes().enumerate() {
+                match b {
+                    b'>' => {
+                        y_lines[y - 1].push(Blizzard {
+                            initial: x as i32 - 1,
+                            movement: 1,
+                        });
+                    }
+                    b'<' => {
+                        y_lines[y - 1].push(Blizzard {
+                            initial: x as i32 - 1,
+                            movement: -1,
+                        });
+                    }
+                    b'^' => {
+                        x_lines[x - 1].push(Blizzard {
+                            initial: y as i32 - 1,
+                            movement: -1,
+                        });
+                    }
+                    b'v' => {
+                        x_lines[x - 1].push(Blizzard {
+                            initial: y as i32 - 1,
+                            movement: 1,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            x_lines,
+            y_lines,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Blizzard {
+    initial: i32,
+    movement: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_at_the_entrance_and_the_exit() {
+        let input = include_str!("../../inputs/2022/day24_test.txt");
+        let params = Params::from([("width", 6), ("height", 4)]);
+
+        assert_eq!((18, 54), run_with(input, &params).unwrap());
+    }
+}
@@ -1,13 +1,29 @@
+use std::io::BufRead;
+
+use tracing::debug;
+
+use crate::utils::for_each_line;
+
 pub fn run(input: &str) -> anyhow::Result<(String, u32)> {
     let sum = input.lines().map(parse_snafu).sum::<Result<i64, _>>()?;
-
-    println!("{}", sum);
+    debug!(sum, "summed fuel requirements");
 
     let snafu = decimal_to_snafu(sum);
 
     Ok((snafu, 0))
 }
 
+/// Streaming variant of `run` that sums each number as it is read
+pub fn run_stream<R: BufRead>(reader: R) -> anyhow::Result<(String, u32)> {
+    let mut sum = 0;
+    for_each_line(reader, |line| {
+        sum += parse_snafu(line.trim())?;
+        Ok(())
+    })?;
+
+    Ok((decimal_to_snafu(sum), 0))
+}
+
 fn parse_snafu(snafu: &str) -> anyhow::Result<i64> {
     snafu.bytes().rev().enumerate().try_fold(0, |acc, (i, b)| {
         let fives = 5i64.pow(i as u32);
@@ -45,20 +61,7 @@ fn decimal_to_snafu(mut num: i64) -> String {
 mod tests {
     use super::*;
 
-    static TEST_INPUT: &str = "\
-1=-0-2
-12111
-2=0=
-21
-2=01
-111
-20012
-112
-1=-1=
-1-12
-12
-1=
-122";
+    static TEST_INPUT: &str = include_str!("../../inputs/2022/day25_test.txt");
 
     #[test]
     fn snafu_parser_works() {
@@ -0,0 +1,1048 @@
+//! Seedable generators of random, valid puzzle inputs.
+//!
+//! Every generator takes a [`Rng`] and a `size` and returns an input in the same format as the real
+//! puzzle input, including the trailing newline. What `size` controls differs from day to day and
+//! is documented on each generator, the `default_size` of each [`Generator`] matches the real input.
+
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use hashbrown::HashSet;
+
+/// A small, seedable PRNG (SplitMix64). It isn't suitable for anything other than generating test
+/// inputs, but it is fast and the same seed will always produce the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`, `n` must be greater than 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        lo + self.below((hi - lo + 1) as usize) as i64
+    }
+
+    /// Returns true with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+pub struct Generator {
+    pub name: &'static str,
+    /// The size of the real puzzle input
+    pub default_size: usize,
+    /// Whether the day's `run` only accepts inputs generated with `default_size`, e.g. because the
+    /// dimensions of the grid are hard-coded
+    pub fixed_size: bool,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn seeded(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+macro_rules! generators {
+    ($($day:ident => ($default_size:expr, $fixed_size:expr)),* $(,)?) => {
+        pub static GENERATORS: &[Generator] = &[
+            $(Generator {
+                name: std::stringify!($day),
+                default_size: $default_size,
+                fixed_size: $fixed_size,
+                generate: $day,
+            }),*
+        ];
+    };
+}
+
+generators!(
+    day01 => (250, false),
+    day02 => (2500, false),
+    day03 => (300, false),
+    day04 => (1000, false),
+    day05 => (502, false),
+    day06 => (4096, false),
+    day07 => (180, false),
+    day08 => (99, true),
+    day08_par => (99, true),
+    day09 => (2000, false),
+    day10 => (137, false),
+    day11 => (8, false),
+    day12 => (80, true),
+    day13 => (150, false),
+    day14 => (112, false),
+    day15 => (20, false),
+    day16 => (51, false),
+    day17 => (10091, false),
+    day18 => (2742, false),
+    day19 => (30, false),
+    day20 => (5000, false),
+    day21 => (1835, false),
+    day22 => (2000, true),
+    day23 => (73, false),
+    day24 => (100, true),
+    day25 => (127, false),
+);
+
+pub fn find(name: &str) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.name == name)
+}
+
+const LOWERCASE: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn word(rng: &mut Rng, len: RangeInclusive<i64>) -> String {
+    let len = rng.range(len) as usize;
+    (0..len).map(|_| *rng.choose(LOWERCASE) as char).collect()
+}
+
+/// `size` elves
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| format!("{}\n", rng.range(1000..=70000)))
+                .collect()
+        })
+        .collect();
+
+    elves.join("\n")
+}
+
+/// `size` rounds
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                *rng.choose(b"ABC") as char,
+                *rng.choose(b"XYZ") as char
+            )
+        })
+        .collect()
+}
+
+/// `size` rucksacks, rounded up to a multiple of 3 so that every elf is in a group
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size.div_ceil(3) {
+        let mut letters = *LETTERS;
+        rng.shuffle(&mut letters);
+
+        let badge = letters[0];
+
+        // Give each elf in the group its own letters so that the badge is the only item they all
+        // have in common, the first letter is shared between the two compartments unless the
+        // badge is.
+        for pool in letters[1..].chunks(17) {
+            let shared = if rng.chance(0.25) { badge } else { pool[0] };
+            let (left_pool, right_pool) = pool[1..].split_at(8);
+            let len = rng.range(2..=16) as usize;
+
+            let mut left = vec![shared];
+            let mut right = vec![shared];
+            left.extend((1..len).map(|_| *rng.choose(left_pool)));
+            right.extend((1..len).map(|_| *rng.choose(right_pool)));
+
+            if shared != badge {
+                let compartment = if rng.chance(0.5) {
+                    &mut left
+                } else {
+                    &mut right
+                };
+                compartment[1] = badge;
+            }
+
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+
+            out.extend(left.into_iter().chain(right).map(char::from));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// `size` pairs of elves
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    let assignment = |rng: &mut Rng| {
+        let (a, b) = (rng.range(1..=99), rng.range(1..=99));
+        (a.min(b), a.max(b))
+    };
+
+    (0..size)
+        .map(|_| {
+            let (a, b) = (assignment(rng), assignment(rng));
+            format!("{}-{},{}-{}\n", a.0, a.1, b.0, b.1)
+        })
+        .collect()
+}
+
+/// `size` moves across 9 stacks, some of which may start empty. Every move only takes as many
+/// crates as are on the stack it is moving from.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    const STACKS: usize = 9;
+
+    let mut stacks: Vec<Vec<u8>> = (0..STACKS)
+        .map(|_| {
+            (0..rng.range(0..=8))
+                .map(|_| rng.range(b'A' as i64..=b'Z' as i64) as u8)
+                .collect()
+        })
+        .collect();
+
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[rng.below(STACKS)].push(b'A');
+    }
+
+    let mut out = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(&supply) => format!("[{}]", supply as char),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        writeln!(out, "{}", cells.join(" ")).unwrap();
+    }
+
+    let footer: Vec<String> = (1..=STACKS).map(|i| format!(" {} ", i)).collect();
+    writeln!(out, "{}\n", footer.join(" ")).unwrap();
+
+    // Only the heights matter to whether a move is valid, and those are the same for both crane
+    // models.
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+    for _ in 0..size {
+        let non_empty: Vec<usize> = (0..STACKS).filter(|&i| heights[i] > 0).collect();
+        let from = *rng.choose(&non_empty);
+        let to = (from + 1 + rng.below(STACKS - 1)) % STACKS;
+        let amount = 1 + rng.below(heights[from]);
+
+        heights[from] -= amount;
+        heights[to] += amount;
+
+        writeln!(out, "move {} from {} to {}", amount, from + 1, to + 1).unwrap();
+    }
+
+    out
+}
+
+/// A datastream of `size` letters (at least 14) with both a start-of-packet and a
+/// start-of-message marker
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+
+    // A prefix drawn from 3 letters can't contain either marker
+    let mut alphabet = *LOWERCASE;
+    rng.shuffle(&mut alphabet);
+    let prefix_len = rng.below(size - 14 + 1);
+    let mut out: String = (0..prefix_len)
+        .map(|_| *rng.choose(&alphabet[..3]) as char)
+        .collect();
+
+    rng.shuffle(&mut alphabet);
+    out.extend(alphabet[..14].iter().map(|&b| b as char));
+    out.extend((out.len()..size).map(|_| *rng.choose(LOWERCASE) as char));
+    out.push('\n');
+
+    out
+}
+
+/// A terminal session exploring `size` directories, occasionally running `cd ..` at the root. The
+/// total size of the files is always large enough that something has to be deleted for the update.
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    struct Dir {
+        name: String,
+        children: Vec<usize>,
+        files: Vec<(u64, String)>,
+    }
+
+    let mut dirs = vec![Dir {
+        name: "/".to_owned(),
+        children: Vec::new(),
+        files: Vec::new(),
+    }];
+
+    for i in 1..size.max(1) {
+        let parent = rng.below(i);
+        let name = loop {
+            let name = word(rng, 1..=8);
+            if dirs[parent].children.iter().all(|&c| dirs[c].name != name) {
+                break name;
+            }
+        };
+
+        dirs[parent].children.push(i);
+        dirs.push(Dir {
+            name,
+            children: Vec::new(),
+            files: Vec::new(),
+        });
+    }
+
+    for dir in dirs.iter_mut() {
+        for _ in 0..rng.range(0..=5) {
+            let name = loop {
+                let mut name = word(rng, 1..=8);
+                if rng.chance(0.5) {
+                    write!(name, ".{}", word(rng, 1..=3)).unwrap();
+                }
+                if dir.files.iter().all(|(_, f)| *f != name) {
+                    break name;
+                }
+            };
+            dir.files.push((rng.range(1..=300_000) as u64, name));
+        }
+    }
+
+    if dirs.iter().all(|dir| dir.files.is_empty()) {
+        dirs[0].files.push((1, "a".to_owned()));
+    }
+
+    // Scale the file sizes so that the total used space is between 40,000,000 and 70,000,000
+    let total: u64 = dirs.iter().flat_map(|d| d.files.iter()).map(|f| f.0).sum();
+    let target = rng.range(41_000_000..=69_000_000) as u64;
+    for dir in dirs.iter_mut() {
+        for file in dir.files.iter_mut() {
+            file.0 = (file.0 * target / total).max(1);
+        }
+    }
+    let total: u64 = dirs.iter().flat_map(|d| d.files.iter()).map(|f| f.0).sum();
+    if let Some(file) = dirs.iter_mut().flat_map(|d| d.files.iter_mut()).next() {
+        file.0 += target.saturating_sub(total);
+    }
+
+    fn explore(dirs: &[Dir], idx: usize, rng: &mut Rng, out: &mut String) {
+        let dir = &dirs[idx];
+        writeln!(out, "$ cd {}\n$ ls", dir.name).unwrap();
+
+        let mut listing: Vec<String> = dir
+            .children
+            .iter()
+            .map(|&c| format!("dir {}", dirs[c].name))
+            .chain(
+                dir.files
+                    .iter()
+                    .map(|(size, name)| format!("{} {}", size, name)),
+            )
+            .collect();
+        rng.shuffle(&mut listing);
+        for line in listing {
+            writeln!(out, "{}", line).unwrap();
+        }
+
+        for (i, &child) in dir.children.iter().enumerate() {
+            explore(dirs, child, rng, out);
+            // No need to go back up after the very last directory
+            if idx != 0 || i + 1 < dir.children.len() {
+                out.push_str("$ cd ..\n");
+            }
+            if idx == 0 && i + 1 < dir.children.len() && rng.chance(0.25) {
+                out.push_str("$ cd ..\n");
+            }
+        }
+    }
+
+    let mut out = String::new();
+    explore(&dirs, 0, rng, &mut out);
+
+    out
+}
+
+/// A `size` by `size` forest
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| (b'0' + rng.below(10) as u8) as char)
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+pub fn day08_par(rng: &mut Rng, size: usize) -> String {
+    day08(rng, size)
+}
+
+/// `size` moves of the head of the rope
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}\n", *rng.choose(b"RLUD") as char, rng.range(1..=20)))
+        .collect()
+}
+
+/// `size` instructions, or however many more are needed to draw all 240 pixels. The register is
+/// kept within the bounds of the screen.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let mut register = 1;
+    let mut cycles = 0;
+    let mut instrs = 0;
+
+    while instrs < size || cycles < 240 {
+        if rng.chance(0.4) {
+            out.push_str("noop\n");
+            cycles += 1;
+        } else {
+            let next = rng.range(-1..=40);
+            let v = if next == register { 1 } else { next - register };
+            register += v;
+            writeln!(out, "addx {}", v).unwrap();
+            cycles += 2;
+        }
+        instrs += 1;
+    }
+
+    out
+}
+
+/// `size` monkeys, clamped to between 3 and 9 so that the product of their divisors can be squared
+/// without overflowing. Multipliers are kept small so that part 1's worry levels can't grow without
+/// bound.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    let size = size.clamp(3, PRIMES.len());
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let monkeys: Vec<String> = (0..size)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.range(1..=6))
+                .map(|_| rng.range(50..=99).to_string())
+                .collect();
+            let operation = if rng.chance(0.5) {
+                format!("old + {}", rng.range(1..=8))
+            } else {
+                format!("old * {}", rng.range(2..=3))
+            };
+            let others: Vec<usize> = (0..size).filter(|&j| j != i).collect();
+            let yes = *rng.choose(&others);
+            let no = loop {
+                let no = *rng.choose(&others);
+                if no != yes {
+                    break no;
+                }
+            };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i,
+                items.join(", "),
+                operation,
+                primes[i],
+                yes,
+                no
+            )
+        })
+        .collect();
+
+    monkeys.join("\n")
+}
+
+/// A heightmap `size` squares wide and `size / 2 + 1` tall (at least 26 wide). Neighbouring
+/// squares never differ by more than 1, so every square can reach every other one.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = width / 2 + 1;
+
+    // Heights increase with x and with a per-row offset that changes by at most 1 between rows
+    let mut offsets = vec![0usize; height];
+    for y in 1..height {
+        offsets[y] = match rng.below(3) {
+            0 => offsets[y - 1].saturating_sub(1),
+            1 => offsets[y - 1],
+            _ => offsets[y - 1] + 1,
+        };
+    }
+
+    let max_d = width - 1 + offsets.iter().max().unwrap();
+    let mut ds: Vec<usize> = (1..=max_d).collect();
+    rng.shuffle(&mut ds);
+    let mut steps = vec![0u8; max_d + 1];
+    for &d in &ds[..25] {
+        steps[d] = 1;
+    }
+    for d in 1..=max_d {
+        steps[d] += steps[d - 1];
+    }
+
+    let mut grid: Vec<Vec<u8>> = (0..height)
+        .map(|y| (0..width).map(|x| b'a' + steps[x + offsets[y]]).collect())
+        .collect();
+
+    let cells_of = |grid: &[Vec<u8>], height: u8| -> Vec<(usize, usize)> {
+        (0..grid.len())
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&(x, y)| grid[y][x] == height)
+            .collect()
+    };
+
+    let (sx, sy) = *rng.choose(&cells_of(&grid, b'a'));
+    let (ex, ey) = *rng.choose(&cells_of(&grid, b'z'));
+    grid[sy][sx] = b'S';
+    grid[ey][ex] = b'E';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+/// `size` pairs of packets
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize, out: &mut String) {
+        out.push('[');
+        for i in 0..rng.range(0..=5) {
+            if i > 0 {
+                out.push(',');
+            }
+            if depth < 4 && rng.chance(0.3) {
+                packet(rng, depth + 1, out);
+            } else {
+                write!(out, "{}", rng.range(0..=10)).unwrap();
+            }
+        }
+        out.push(']');
+    }
+
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut pair = String::new();
+            packet(rng, 0, &mut pair);
+            pair.push('\n');
+            packet(rng, 0, &mut pair);
+            pair.push('\n');
+            pair
+        })
+        .collect();
+
+    pairs.join("\n")
+}
+
+/// `size` rock paths. Rocks are never closer to the top than they are wide, so the sand always
+/// falls into the abyss before it can block the source.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    let half_width = 10 + size as i64 / 4;
+    let top = half_width + 2;
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut x = 500 + rng.range(-half_width..=half_width);
+            let mut y = rng.range(top..=top + 150);
+            let mut points = vec![format!("{},{}", x, y)];
+
+            for i in 0..rng.range(1..=5) {
+                if i % 2 == 0 {
+                    x = (x + rng.range(-8..=8)).clamp(500 - half_width, 500 + half_width);
+                } else {
+                    y = (y + rng.range(-8..=8)).max(top);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// The four bounding sensors that leave exactly one position uncovered in the search area used by
+/// `run`, plus `size` extra sensors that don't reach it.
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    day15_with_bound(rng, size, 4_000_000)
+}
+
+/// Like [`day15`], but the distress beacon is somewhere in `0..=bound`. Every position in the
+/// search area apart from the distress beacon is covered by one of the four bounding sensors, so
+/// the extra sensors are free to overlap them.
+pub fn day15_with_bound(rng: &mut Rng, size: usize, bound: i64) -> String {
+    let mut out = String::new();
+    let mut push = |sensor: (i64, i64), beacon: (i64, i64)| {
+        writeln!(
+            out,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0, sensor.1, beacon.0, beacon.1
+        )
+        .unwrap()
+    };
+
+    let (px, py) = (rng.range(0..=bound), rng.range(0..=bound));
+
+    // In rotated coordinates (u = x + y, v = x - y) a sensor's range is a square, so the search
+    // area minus the beacon can be covered by a square either side of it in u, and a square either
+    // side of it in v along the line u = pu. Radii must be odd for the centres to be whole.
+    let (pu, pv) = (px + py, px - py);
+    let to_xy = |(u, v): (i64, i64)| ((u + v) / 2, (u - v) / 2);
+    let mut radius = || 2 * bound + 1 + 2 * rng.range(0..=bound / 2);
+
+    let r = radius();
+    let centre = (pu - 1 - r, pv);
+    push(to_xy(centre), to_xy((centre.0 - r, centre.1 - 1)));
+
+    let r = radius();
+    let centre = (pu + 1 + r, pv);
+    push(to_xy(centre), to_xy((centre.0 + r, centre.1 - 1)));
+
+    let r = radius();
+    let centre = (pu, pv - 1 - r);
+    push(to_xy(centre), to_xy((centre.0 - 1, centre.1 - r)));
+
+    let r = radius();
+    let centre = (pu, pv + 1 + r);
+    push(to_xy(centre), to_xy((centre.0 - 1, centre.1 + r)));
+
+    for _ in 0..size {
+        let (sx, sy) = loop {
+            let sensor = (rng.range(0..=bound), rng.range(0..=bound));
+            if sensor != (px, py) {
+                break sensor;
+            }
+        };
+        let distance = (sx - px).abs() + (sy - py).abs();
+        let r = rng.range(0..=distance - 1);
+        let dx = rng.range(-r..=r);
+        let dy = (r - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        push((sx, sy), (sx + dx, sy + dy));
+    }
+
+    out
+}
+
+/// `size` valves (at least 2), about a third of which have a non-zero flow rate, capped at 15 to
+/// keep the state space manageable. Every valve has at most 5 tunnels.
+pub fn day16(rng: &mut Rng, size: usize) -> String {
+    const MAX_EDGES: usize = 5;
+
+    let size = size.clamp(2, 26 * 26);
+    let mut names: Vec<String> = vec!["AA".to_owned()];
+    let mut seen: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < size {
+        let name: String = (0..2)
+            .map(|_| rng.range(b'A' as i64..=b'Z' as i64) as u8 as char)
+            .collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut edges: Vec<Vec<usize>> = vec![Vec::new(); size];
+    // A random spanning tree keeps the cave connected, then add some loops
+    for i in 1..size {
+        let parent = loop {
+            let parent = rng.below(i);
+            if edges[parent].len() < MAX_EDGES {
+                break parent;
+            }
+        };
+        edges[parent].push(i);
+        edges[i].push(parent);
+    }
+    for _ in 0..size / 2 {
+        let (a, b) = (rng.below(size), rng.below(size));
+        if a != b
+            && edges[a].len() < MAX_EDGES
+            && edges[b].len() < MAX_EDGES
+            && !edges[a].contains(&b)
+        {
+            edges[a].push(b);
+            edges[b].push(a);
+        }
+    }
+
+    let mut flows = vec![0; size];
+    let mut candidates: Vec<usize> = (1..size).collect();
+    rng.shuffle(&mut candidates);
+    for &i in candidates.iter().take((size / 3).clamp(1, 15)) {
+        flows[i] = rng.range(1..=25);
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    rng.shuffle(&mut order);
+
+    order
+        .into_iter()
+        .map(|i| {
+            let tunnels: Vec<&str> = edges[i].iter().map(|&j| names[j].as_str()).collect();
+            let tunnels = if tunnels.len() == 1 {
+                format!("tunnel leads to valve {}", tunnels[0])
+            } else {
+                format!("tunnels lead to valves {}", tunnels.join(", "))
+            };
+            format!(
+                "Valve {} has flow rate={}; {}\n",
+                names[i], flows[i], tunnels
+            )
+        })
+        .collect()
+}
+
+/// `size` jets
+pub fn day17(rng: &mut Rng, size: usize) -> String {
+    let mut out: String = (0..size.max(1))
+        .map(|_| *rng.choose(b"<>") as char)
+        .collect();
+    out.push('\n');
+    out
+}
+
+/// `size` distinct cubes scattered over a box large enough that a quarter of it is lava. The
+/// origin is always outside of the droplet.
+pub fn day18(rng: &mut Rng, size: usize) -> String {
+    let side = ((size * 4) as f64).cbrt().ceil() as i64 + 1;
+    let mut cubes = HashSet::new();
+    let mut out = String::new();
+
+    while cubes.len() < size {
+        let cube = (
+            rng.range(1..=side),
+            rng.range(1..=side),
+            rng.range(1..=side),
+        );
+        if cubes.insert(cube) {
+            writeln!(out, "{},{},{}", cube.0, cube.1, cube.2).unwrap();
+        }
+    }
+
+    out
+}
+
+/// `size` blueprints, `run` needs at least 3. Costs are drawn from the same ranges as the real
+/// input, so some blueprints can't open a single geode.
+pub fn day19(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|i| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                i,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(5..=20),
+                rng.range(2..=4),
+                rng.range(5..=20),
+            )
+        })
+        .collect()
+}
+
+/// `size` numbers (at least 2), exactly one of which is 0
+pub fn day20(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let zero = rng.below(size);
+
+    (0..size)
+        .map(|i| {
+            let n = if i == zero {
+                0
+            } else {
+                loop {
+                    let n = rng.range(-10_000..=10_000);
+                    if n != 0 {
+                        break n;
+                    }
+                }
+            };
+            format!("{}\n", n)
+        })
+        .collect()
+}
+
+/// Roughly `size` monkeys. `humn` is as likely to be on the right of `root` as on the left, every
+/// division is exact, and there is always a number to yell that balances `root`.
+pub fn day21(rng: &mut Rng, size: usize) -> String {
+    enum Node {
+        Value(i64),
+        Maths(usize, char, usize),
+    }
+
+    // Builds a random expression of about `budget` monkeys and returns its index and value
+    fn expression(rng: &mut Rng, budget: usize, nodes: &mut Vec<Node>) -> (usize, i64) {
+        if budget <= 1 {
+            let value = rng.range(1..=20);
+            nodes.push(Node::Value(value));
+            return (nodes.len() - 1, value);
+        }
+
+        let left_budget = 1 + rng.below(budget - 1);
+        let (l, a) = expression(rng, left_budget, nodes);
+        let (r, b) = expression(rng, budget - left_budget, nodes);
+
+        let (op, value) = match rng.below(4) {
+            0 if a.abs() < 1_000_000 && b.abs() < 1_000_000 => ('*', a * b),
+            1 if b != 0 && a % b == 0 => ('/', a / b),
+            2 => ('-', a - b),
+            _ => ('+', a + b),
+        };
+        nodes.push(Node::Maths(l, op, r));
+        (nodes.len() - 1, value)
+    }
+
+    let mut nodes = vec![Node::Value(rng.range(1..=5000))];
+    let humn = 0;
+
+    let depth = (size / 20).clamp(1, 40);
+    let sibling_budget = (size.saturating_sub(2 * depth + 3) / depth).max(1);
+
+    // Walk up from `humn` with the value that balances `root`, so that everything along the way is
+    // exact. Multipliers on the path are kept small so that part 2's search can't overflow.
+    let (mut current, mut value) = (humn, rng.range(1..=5000));
+    let mut coefficient = 1;
+
+    for _ in 0..depth {
+        let humn_on_left = rng.chance(0.5);
+        let (op, sibling, s) = if coefficient < 512 && rng.chance(0.2) {
+            let s = rng.range(2..=4);
+            nodes.push(Node::Value(s));
+            coefficient *= s;
+            ('*', nodes.len() - 1, s)
+        } else {
+            let (sibling, s) = expression(rng, sibling_budget, &mut nodes);
+            (*rng.choose(&['+', '-']), sibling, s)
+        };
+
+        value = match (op, humn_on_left) {
+            ('*', _) => value * s,
+            ('+', _) => value + s,
+            ('-', true) => value - s,
+            _ => s - value,
+        };
+
+        let node = if humn_on_left {
+            Node::Maths(current, op, sibling)
+        } else {
+            Node::Maths(sibling, op, current)
+        };
+        nodes.push(node);
+        current = nodes.len() - 1;
+    }
+
+    let (other, w) = expression(rng, sibling_budget, &mut nodes);
+    nodes.push(Node::Value((value - w).abs()));
+    let op = if value >= w { '+' } else { '-' };
+    nodes.push(Node::Maths(other, op, nodes.len() - 1));
+    let other = nodes.len() - 1;
+
+    let root = if rng.chance(0.5) {
+        Node::Maths(current, '+', other)
+    } else {
+        Node::Maths(other, '+', current)
+    };
+    nodes.push(root);
+    let root = nodes.len() - 1;
+
+    let mut names = vec![String::new(); nodes.len()];
+    let mut seen: HashSet<String> = ["root".to_owned(), "humn".to_owned()].into();
+    for (i, name) in names.iter_mut().enumerate() {
+        *name = match i {
+            _ if i == root => "root".to_owned(),
+            _ if i == humn => "humn".to_owned(),
+            _ => loop {
+                let name = word(rng, 4..=4);
+                if seen.insert(name.clone()) {
+                    break name;
+                }
+            },
+        };
+    }
+
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    rng.shuffle(&mut order);
+
+    order
+        .into_iter()
+        .map(|i| match nodes[i] {
+            Node::Value(v) => format!("{}: {}\n", names[i], v),
+            Node::Maths(l, op, r) => format!("{}: {} {} {}\n", names[i], names[l], op, names[r]),
+        })
+        .collect()
+}
+
+/// A map folded the same way as the real input (six 50 by 50 faces), followed by `size` steps
+pub fn day22(rng: &mut Rng, size: usize) -> String {
+    const FACE: usize = 50;
+    // The columns of faces in each row of faces
+    const LAYOUT: [(usize, usize); 4] = [(1, 3), (1, 2), (0, 2), (0, 1)];
+
+    let mut out = String::new();
+
+    for (y, &(start, end)) in LAYOUT.iter().flat_map(|row| [row; FACE]).enumerate() {
+        for x in 0..end * FACE {
+            let tile = if x < start * FACE {
+                ' '
+            } else if (x, y) != (FACE, 0) && rng.chance(0.1) {
+                '#'
+            } else {
+                '.'
+            };
+            out.push(tile);
+        }
+        out.push('\n');
+    }
+
+    out.push('\n');
+    for i in 0..size.max(1) {
+        if i > 0 {
+            out.push(*rng.choose(&['L', 'R']));
+        }
+        write!(out, "{}", rng.range(1..=50)).unwrap();
+    }
+    out.push('\n');
+
+    out
+}
+
+/// A `size` by `size` grove with about half of the positions occupied by elves
+pub fn day23(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+/// A valley `size` positions wide and `7 * size / 20` tall (at least 3 by 2), with blizzards
+/// covering three quarters of it. No blizzards move vertically through the entrance or exit.
+pub fn day24(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(3);
+    let height = (7 * width / 20).max(2);
+
+    let mut out = String::new();
+    writeln!(out, "#.{}", "#".repeat(width)).unwrap();
+    for _ in 0..height {
+        out.push('#');
+        for x in 0..width {
+            let directions: &[char] = if x == 0 || x == width - 1 {
+                &['<', '>']
+            } else {
+                &['<', '>', '^', 'v']
+            };
+            if rng.chance(0.75) {
+                out.push(*rng.choose(directions));
+            } else {
+                out.push('.');
+            }
+        }
+        out.push_str("#\n");
+    }
+    writeln!(out, "{}.#", "#".repeat(width)).unwrap();
+
+    out
+}
+
+/// `size` positive SNAFU numbers
+pub fn day25(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut n = rng.range(1..=1_000_000_000_000);
+            let mut snafu = Vec::new();
+            while n > 0 {
+                snafu.push(b"=-012"[((n + 2) % 5) as usize]);
+                n = (n + 2) / 5;
+            }
+            snafu.reverse();
+            String::from_utf8(snafu).unwrap() + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generators_are_deterministic() {
+        for generator in GENERATORS {
+            let size = generator.default_size.min(50);
+
+            assert_eq!(
+                generator.seeded(7, size),
+                generator.seeded(7, size),
+                "{}",
+                generator.name
+            );
+        }
+    }
+
+    #[test]
+    fn rng_below_stays_in_range() {
+        let mut rng = Rng::new(0);
+
+        for n in 1..100 {
+            assert!(rng.below(n) < n);
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        use crate::y2022::*;
+
+        for seed in 0..4 {
+            let input = |name: &str, size: usize| find(name).unwrap().seeded(seed, size);
+
+            day01::run(&input("day01", 50)).unwrap();
+            day02::run(&input("day02", 50)).unwrap();
+            day03::run(&input("day03", 50)).unwrap();
+            day04::run(&input("day04", 50)).unwrap();
+            day05::run(&input("day05", 50)).unwrap();
+            day06::run(&input("day06", 50)).unwrap();
+            day07::run(&input("day07", 50)).unwrap();
+            day08::run(&input("day08", 99)).unwrap();
+            day08_par::run(&input("day08_par", 99)).unwrap();
+            day09::run(&input("day09", 50)).unwrap();
+            day10::run(&input("day10", 50)).unwrap();
+            day11::run(&input("day11", 5)).unwrap();
+            day12::run(&input("day12", 80)).unwrap();
+            day13::run(&input("day13", 50)).unwrap();
+            day14::run(&input("day14", 20)).unwrap();
+            day16::run(&input("day16", 12)).unwrap();
+            day18::run(&input("day18", 50)).unwrap();
+            day20::run(&input("day20", 50)).unwrap();
+            day21::run(&input("day21", 100)).unwrap();
+            day22::run(&input("day22", 50)).unwrap();
+            day23::run(&input("day23", 10)).unwrap();
+            day25::run(&input("day25", 50)).unwrap();
+        }
+    }
+}
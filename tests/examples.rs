@@ -0,0 +1,87 @@
+//! Runs every example in `inputs/examples.toml` through the registry, as a test of its own, so that
+//! adding an example only takes an entry in the manifest.
+
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::anyhow;
+use aoc2022::examples::{self, Example, Manifest};
+use aoc2022::{input, registry};
+
+/// libtest flags that take a value, so that the value isn't mistaken for a filter.
+const FLAGS_WITH_VALUES: [&str; 5] = ["--test-threads", "--skip", "--format", "--color", "-Z"];
+
+fn main() -> anyhow::Result<ExitCode> {
+    let filters = filters(std::env::args().skip(1));
+    let dir = input::default_dir();
+    let manifest = Manifest::load(&examples::path(&dir))?;
+
+    // Panics are reported as failures, so the default hook's message would only clutter the output
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut numbers = HashMap::new();
+    let (mut passed, mut failed) = (Vec::new(), Vec::new());
+    for (year, name, example) in manifest.iter() {
+        let number = numbers.entry((year, name)).or_insert(0);
+        *number += 1;
+
+        let id = format!("{}::{}::example_{}", year, name, number);
+        if !filters.is_empty() && !filters.iter().any(|filter| id.contains(filter.as_str())) {
+            continue;
+        }
+
+        match run_example(year, name, example, &dir) {
+            Ok(()) => {
+                println!("test {} ... ok", id);
+                passed.push(id);
+            }
+            Err(err) => {
+                println!("test {} ... FAILED: {:#}", id, err);
+                failed.push(id);
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "test result: {}. {} passed; {} failed",
+        if failed.is_empty() { "ok" } else { "FAILED" },
+        passed.len(),
+        failed.len()
+    );
+
+    Ok(if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn run_example(year: u16, name: &str, example: &Example, dir: &Path) -> anyhow::Result<()> {
+    let solution = registry::find(year, name).ok_or_else(|| anyhow!("no solution for {}", name))?;
+    let input = example.load(dir, year)?;
+
+    let answers = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.solve_with(&input, &example.params)
+    }))
+    .map_err(|_| anyhow!("solution panicked"))??;
+
+    example.check(&answers)
+}
+
+/// The arguments that aren't libtest flags, e.g. `day06` from `cargo test day06`.
+fn filters(mut args: impl Iterator<Item = String>) -> Vec<String> {
+    let mut filters = Vec::new();
+
+    while let Some(arg) = args.next() {
+        if FLAGS_WITH_VALUES.contains(&arg.as_str()) {
+            args.next();
+        } else if !arg.starts_with('-') {
+            filters.push(arg);
+        }
+    }
+
+    filters
+}